@@ -1,4 +1,6 @@
 use anyhow::{anyhow, Result};
+use bitcoin::address::{NetworkUnchecked, Payload};
+use bitcoin::hashes::Hash as _;
 use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
 use bitcoin::{Address, Network, PublicKey as BitcoinPublicKey};
 use num_bigint::BigUint;
@@ -42,6 +44,70 @@ pub fn private_key_to_addresses(private_key: &BigUint) -> Result<Vec<String>> {
     Ok(addresses)
 }
 
+/// Derives the raw 20-byte hash160 (RIPEMD160(SHA256(pubkey))) for the
+/// compressed and uncompressed public keys, without the base58/checksum
+/// encoding that [`private_key_to_addresses`] performs. Intended for hot
+/// loops that need to test candidate keys against a Bloom filter or a
+/// `HashSet<[u8; 20]>` rather than print or compare encoded addresses.
+pub fn private_key_to_hash160s(private_key: &BigUint) -> Result<Vec<[u8; 20]>> {
+    let secp = Secp256k1::new();
+
+    let key_bytes = private_key_to_bytes(private_key)?;
+
+    let secret_key =
+        SecretKey::from_slice(&key_bytes).map_err(|e| anyhow!("Invalid private key: {}", e))?;
+
+    let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+
+    let compressed_pubkey = BitcoinPublicKey::new(public_key);
+    let uncompressed_pubkey = BitcoinPublicKey::new_uncompressed(public_key);
+
+    Ok(vec![
+        compressed_pubkey.pubkey_hash().to_byte_array(),
+        uncompressed_pubkey.pubkey_hash().to_byte_array(),
+    ])
+}
+
+/// Decodes a mainnet P2PKH Bitcoin address into its raw hash160, for loading
+/// large target address dumps into a [`crate::bloom::Hash160BloomFilter`].
+pub fn address_to_hash160(address: &str) -> Result<[u8; 20]> {
+    let unchecked = address
+        .parse::<Address<NetworkUnchecked>>()
+        .map_err(|e| anyhow!("Invalid Bitcoin address '{}': {}", address, e))?;
+
+    let checked = unchecked
+        .require_network(Network::Bitcoin)
+        .map_err(|e| anyhow!("Address '{}' is not a mainnet address: {}", address, e))?;
+
+    match checked.payload() {
+        Payload::PubkeyHash(hash) => Ok(hash.to_byte_array()),
+        _ => Err(anyhow!(
+            "Address '{}' is not a P2PKH address (Bloom matching only supports hash160 targets)",
+            address
+        )),
+    }
+}
+
+/// Parses a 40-character hex string as a raw hash160, for target files that
+/// list hash160s directly instead of encoded addresses.
+pub fn parse_hash160_hex(hex_str: &str) -> Result<[u8; 20]> {
+    if hex_str.len() != 40 {
+        return Err(anyhow!(
+            "hash160 must be exactly 40 hex characters, got {} ('{}')",
+            hex_str.len(),
+            hex_str
+        ));
+    }
+
+    let mut hash160 = [0u8; 20];
+    for (i, byte) in hash160.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex_str[i * 2..i * 2 + 2], 16)
+            .map_err(|e| anyhow!("Invalid hash160 hex '{}': {}", hex_str, e))?;
+    }
+
+    Ok(hash160)
+}
+
 fn private_key_to_bytes(private_key: &BigUint) -> Result<[u8; 32]> {
     let bytes = private_key.to_bytes_be();
 
@@ -95,4 +161,32 @@ mod tests {
         assert_eq!(parse_hex_key("0xFF").unwrap(), BigUint::from(255u32));
         assert_eq!(parse_hex_key("ff").unwrap(), BigUint::from(255u32));
     }
+
+    #[test]
+    fn test_private_key_to_hash160s_matches_addresses() {
+        // The hash160s behind puzzle #3's addresses should match the same
+        // private key's encoded addresses once re-encoded to base58.
+        let private_key = BigUint::from(7u32);
+        let hash160s = private_key_to_hash160s(&private_key).unwrap();
+        assert_eq!(hash160s.len(), 2);
+
+        let target = address_to_hash160("1CUTxxqJWs9FMMSqZgJH6jWNKbKZjNMFLP").unwrap();
+        assert!(hash160s.contains(&target));
+    }
+
+    #[test]
+    fn test_address_to_hash160_rejects_non_p2pkh() {
+        // A Bech32 (P2WPKH) address has no hash160 payload in the sense
+        // Bloom matching needs.
+        assert!(address_to_hash160("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err());
+    }
+
+    #[test]
+    fn test_parse_hash160_hex_round_trip() {
+        let hash160 = address_to_hash160("1CUTxxqJWs9FMMSqZgJH6jWNKbKZjNMFLP").unwrap();
+        let hex_str: String = hash160.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(parse_hash160_hex(&hex_str).unwrap(), hash160);
+
+        assert!(parse_hash160_hex("too_short").is_err());
+    }
 }
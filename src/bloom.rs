@@ -0,0 +1,126 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Space-efficient probabilistic membership test over raw 20-byte hash160
+/// values, used as a prefilter before exact `HashSet` confirmation so
+/// scanning against a large target dump doesn't require base58-encoding
+/// every candidate key.
+///
+/// Bit indices are derived from two independent 64-bit hashes of the
+/// hash160 via double-hashing (`h_i = h1 + i*h2 mod m`), which is
+/// statistically close to using `k` fully independent hash functions while
+/// only needing to hash each value twice.
+pub struct Hash160BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
+
+impl Hash160BloomFilter {
+    /// Sizes the filter for `expected_items` entries at the given false
+    /// positive rate, using the standard formulas `m = -n*ln(p) / (ln 2)^2`
+    /// for the bit array size and `k = (m/n) * ln 2` for the hash count.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let n = (expected_items.max(1)) as f64;
+        let p = false_positive_rate.clamp(f64::EPSILON, 0.5);
+
+        let num_bits = ((-(n * p.ln())) / std::f64::consts::LN_2.powi(2))
+            .ceil()
+            .max(64.0) as u64;
+        let num_hashes = ((num_bits as f64 / n) * std::f64::consts::LN_2)
+            .round()
+            .clamp(1.0, 32.0) as u32;
+
+        Hash160BloomFilter {
+            bits: vec![0u64; (num_bits as usize / 64) + 1],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    fn seeded_hash(hash160: &[u8; 20], seed: u64) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        hash160.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn bit_indices(&self, hash160: &[u8; 20]) -> impl Iterator<Item = u64> + '_ {
+        let h1 = Self::seeded_hash(hash160, 0);
+        let h2 = Self::seeded_hash(hash160, 1);
+        (0..self.num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits)
+    }
+
+    /// Marks `hash160` as present in the filter.
+    pub fn insert(&mut self, hash160: &[u8; 20]) {
+        for idx in self.bit_indices(hash160) {
+            self.bits[(idx / 64) as usize] |= 1 << (idx % 64);
+        }
+    }
+
+    /// Returns `true` if `hash160` might be a member (a possible false
+    /// positive that callers must confirm exactly); `false` means it is
+    /// definitely not a member.
+    pub fn might_contain(&self, hash160: &[u8; 20]) -> bool {
+        self.bit_indices(hash160)
+            .all(|idx| self.bits[(idx / 64) as usize] & (1 << (idx % 64)) != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inserted_items_are_always_found() {
+        let mut filter = Hash160BloomFilter::new(1000, 1e-6);
+        let hash160s: Vec<[u8; 20]> = (0u8..100).map(|i| [i; 20]).collect();
+
+        for hash160 in &hash160s {
+            filter.insert(hash160);
+        }
+
+        for hash160 in &hash160s {
+            assert!(filter.might_contain(hash160));
+        }
+    }
+
+    #[test]
+    fn test_false_positive_rate_is_roughly_bounded() {
+        let mut filter = Hash160BloomFilter::new(1000, 1e-6);
+        let mut inserted = std::collections::HashSet::new();
+
+        for i in 0..1000u32 {
+            let mut hash160 = [0u8; 20];
+            hash160[..4].copy_from_slice(&i.to_be_bytes());
+            filter.insert(&hash160);
+            inserted.insert(hash160);
+        }
+
+        let mut false_positives = 0;
+        let trials = 100_000u32;
+        for i in 1_000_000..1_000_000 + trials {
+            let mut hash160 = [0u8; 20];
+            hash160[..4].copy_from_slice(&i.to_be_bytes());
+            if !inserted.contains(&hash160) && filter.might_contain(&hash160) {
+                false_positives += 1;
+            }
+        }
+
+        // Generous slack over the 1e-6 target: this is a statistical test,
+        // not an exact bound.
+        assert!(
+            (false_positives as f64 / trials as f64) < 0.01,
+            "false positive rate too high: {}/{}",
+            false_positives,
+            trials
+        );
+    }
+
+    #[test]
+    fn test_new_sizes_grow_with_expected_items() {
+        let small = Hash160BloomFilter::new(10, 1e-6);
+        let large = Hash160BloomFilter::new(1_000_000, 1e-6);
+        assert!(large.num_bits > small.num_bits);
+    }
+}
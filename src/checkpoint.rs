@@ -0,0 +1,161 @@
+use crate::random_walk::WalkState;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Persisted state for resuming a sequential `search_range` scan that was
+/// interrupted by `--max-time` or Ctrl-C, so a multi-day puzzle run can be
+/// restarted without rescanning keys it already checked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequentialCheckpoint {
+    pub start_hex: String,
+    pub end_hex: String,
+    pub next_key_hex: String,
+    pub keys_checked: u64,
+    pub targets: Vec<String>,
+}
+
+impl SequentialCheckpoint {
+    /// Loads `path` if it exists and was written for this same range and
+    /// target set; returns `None` (fresh start) otherwise.
+    pub fn load_if_matching(
+        path: &str,
+        start_hex: &str,
+        end_hex: &str,
+        targets: &HashSet<String>,
+    ) -> Result<Option<Self>> {
+        if !Path::new(path).exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(path)?;
+        let checkpoint: SequentialCheckpoint = serde_json::from_str(&contents)?;
+        let checkpoint_targets: HashSet<String> = checkpoint.targets.iter().cloned().collect();
+
+        if checkpoint.start_hex == start_hex
+            && checkpoint.end_hex == end_hex
+            && checkpoint_targets == *targets
+        {
+            Ok(Some(checkpoint))
+        } else {
+            println!(
+                "⚠️  Checkpoint '{}' doesn't match this range/targets, starting fresh",
+                path
+            );
+            Ok(None)
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Persisted state for resuming an adaptive random walk search.
+///
+/// A random walk doesn't enumerate the range sequentially, so "resuming"
+/// doesn't mean continuing from a single `next_key`: it means re-seeding each
+/// walk exactly as before (`seed`/`rng_backend`) and restarting it from its
+/// own [`WalkState`] (position, adaptation state, and learned step weights)
+/// instead of rolling a fresh random starting position and losing everything
+/// the sampler had learned so far.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomWalkCheckpoint {
+    pub start_hex: String,
+    pub end_hex: String,
+    pub targets: Vec<String>,
+    pub seed: Option<u64>,
+    pub rng_backend: String,
+    pub keys_checked: u64,
+    pub walk_states: Vec<WalkState>,
+}
+
+impl RandomWalkCheckpoint {
+    pub fn load_if_matching(
+        path: &str,
+        start_hex: &str,
+        end_hex: &str,
+        targets: &HashSet<String>,
+    ) -> Result<Option<Self>> {
+        if !Path::new(path).exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(path)?;
+        let checkpoint: RandomWalkCheckpoint = serde_json::from_str(&contents)?;
+        let checkpoint_targets: HashSet<String> = checkpoint.targets.iter().cloned().collect();
+
+        if checkpoint.start_hex == start_hex
+            && checkpoint.end_hex == end_hex
+            && checkpoint_targets == *targets
+        {
+            Ok(Some(checkpoint))
+        } else {
+            println!(
+                "⚠️  Checkpoint '{}' doesn't match this range/targets, starting fresh",
+                path
+            );
+            Ok(None)
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_checkpoint_round_trip() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("btc_puzzle_hunter_test_sequential_checkpoint.json");
+        let path_str = path.to_str().unwrap();
+
+        let targets: HashSet<String> = ["1CUTxxqJWs9FMMSqZgJH6jWNKbKZjNMFLP".to_string()]
+            .into_iter()
+            .collect();
+
+        let checkpoint = SequentialCheckpoint {
+            start_hex: "1".to_string(),
+            end_hex: "ff".to_string(),
+            next_key_hex: "80".to_string(),
+            keys_checked: 42,
+            targets: targets.iter().cloned().collect(),
+        };
+        checkpoint.save(path_str).unwrap();
+
+        let loaded = SequentialCheckpoint::load_if_matching(path_str, "1", "ff", &targets)
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.next_key_hex, "80");
+        assert_eq!(loaded.keys_checked, 42);
+
+        let mismatched = SequentialCheckpoint::load_if_matching(path_str, "1", "fe", &targets)
+            .unwrap();
+        assert!(mismatched.is_none());
+
+        let _ = fs::remove_file(path_str);
+    }
+
+    #[test]
+    fn test_sequential_checkpoint_missing_file_returns_none() {
+        let targets = HashSet::new();
+        let result = SequentialCheckpoint::load_if_matching(
+            "/tmp/btc_puzzle_hunter_test_checkpoint_does_not_exist.json",
+            "1",
+            "ff",
+            &targets,
+        )
+        .unwrap();
+        assert!(result.is_none());
+    }
+}
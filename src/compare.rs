@@ -0,0 +1,347 @@
+use crate::bitcoin_utils::private_key_to_addresses;
+use crate::random_walk::{adaptive_random_walk_search, LevyParams, RngBackend, WalkRng};
+use anyhow::{anyhow, Result};
+use num_bigint::BigUint;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A synthetic puzzle planted by picking a random private key inside a
+/// `bits`-wide range and deriving its address, the same way a real unsolved
+/// puzzle pairs a range with a target address — except here the key is
+/// known up front, so a trial can tell whether and how fast each algorithm
+/// actually finds it.
+pub struct PlantedPuzzle {
+    pub range_start: BigUint,
+    pub range_end: BigUint,
+    pub private_key_hex: String,
+    pub address: String,
+}
+
+/// Plants a puzzle in the range `[2^(bits-1), 2^bits - 1]`, mirroring how the
+/// real puzzle series defines its bit widths.
+pub fn generate_planted_puzzle<R: Rng>(bits: u32, rng: &mut R) -> Result<PlantedPuzzle> {
+    if bits == 0 {
+        return Err(anyhow!("--bits must be at least 1"));
+    }
+
+    let range_start = if bits == 1 {
+        BigUint::from(1u32)
+    } else {
+        BigUint::from(1u32) << (bits - 1)
+    };
+    let range_end = (BigUint::from(1u32) << bits) - 1u32;
+    let range_size = &range_end - &range_start + 1u32;
+
+    let offset = BigUint::from(rng.r#gen::<u64>()) % &range_size;
+    let private_key = &range_start + offset;
+
+    let address = private_key_to_addresses(&private_key)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No address could be derived for the planted key"))?;
+
+    Ok(PlantedPuzzle {
+        range_start,
+        range_end,
+        private_key_hex: format!("{:064x}", private_key),
+        address,
+    })
+}
+
+/// Outcome of a single algorithm trial against one planted puzzle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialResult {
+    pub bits: u32,
+    pub algorithm: String,
+    pub found: bool,
+    pub keys_checked: u64,
+    pub wall_time_secs: f64,
+}
+
+/// Scans sequentially from `range_start`, stopping at `key_budget` keys
+/// checked or a match, whichever comes first — the same key-by-key loop
+/// `main.rs`'s `search_batch` uses, but bounded by a budget instead of a
+/// subrange end so it's comparable across bit widths.
+fn run_sequential_trial(puzzle: &PlantedPuzzle, key_budget: u64) -> TrialResult {
+    let start_time = Instant::now();
+    let mut current = puzzle.range_start.clone();
+    let mut keys_checked = 0u64;
+    let mut found = false;
+
+    while keys_checked < key_budget && current <= puzzle.range_end {
+        if let Ok(addresses) = private_key_to_addresses(&current) {
+            keys_checked += 1;
+            if addresses.iter().any(|a| a == &puzzle.address) {
+                found = true;
+                break;
+            }
+        } else {
+            keys_checked += 1;
+        }
+        current += 1u32;
+    }
+
+    TrialResult {
+        bits: 0, // filled in by the caller, which knows the sweep's bit width
+        algorithm: "sequential".to_string(),
+        found,
+        keys_checked,
+        wall_time_secs: start_time.elapsed().as_secs_f64(),
+    }
+}
+
+/// Runs a single adaptive random walk (not the multi-walk parallel driver),
+/// bounded by `key_budget` iterations, so each trial is directly comparable
+/// to `run_sequential_trial`'s single-threaded key count.
+fn run_random_walk_trial(puzzle: &PlantedPuzzle, key_budget: u64, seed: u64) -> TrialResult {
+    let targets: HashSet<String> = [puzzle.address.clone()].into_iter().collect();
+    let mut rng = WalkRng::seed_from_u64(RngBackend::ChaCha20, seed);
+    let adapt_interval = (key_budget as usize / 10).max(1);
+    let keys_checked = Arc::new(AtomicU64::new(0));
+
+    let start_time = Instant::now();
+    let outcome = adaptive_random_walk_search(
+        &puzzle.range_start,
+        &puzzle.range_end,
+        &targets,
+        key_budget as usize,
+        adapt_interval,
+        &mut rng,
+        LevyParams::default(),
+        None,
+        Some(keys_checked.clone()),
+        Arc::new(AtomicBool::new(false)),
+        None,
+    );
+    let wall_time_secs = start_time.elapsed().as_secs_f64();
+
+    let found = matches!(outcome, Ok(ref outcome) if outcome.found.is_some());
+
+    TrialResult {
+        bits: 0,
+        algorithm: "random_walk".to_string(),
+        found,
+        keys_checked: keys_checked.load(Ordering::Relaxed),
+        wall_time_secs,
+    }
+}
+
+/// Aggregate stats for one algorithm at one bit width, computed over all its
+/// trials' `TrialResult`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgorithmStats {
+    pub algorithm: String,
+    pub bits: u32,
+    pub trials: usize,
+    pub success_rate: f64,
+    pub mean_keys_checked: f64,
+    pub median_keys_checked: f64,
+    pub p95_keys_checked: f64,
+    pub mean_wall_time_secs: f64,
+    pub keys_per_second: f64,
+}
+
+fn percentile(sorted_values: &[f64], p: f64) -> f64 {
+    if sorted_values.is_empty() {
+        return 0.0;
+    }
+    let rank = (p * (sorted_values.len() - 1) as f64).round() as usize;
+    sorted_values[rank.min(sorted_values.len() - 1)]
+}
+
+fn summarize(algorithm: &str, bits: u32, trials: &[TrialResult]) -> AlgorithmStats {
+    let trial_count = trials.len();
+    let successes = trials.iter().filter(|t| t.found).count();
+
+    let mut keys_checked: Vec<f64> = trials.iter().map(|t| t.keys_checked as f64).collect();
+    keys_checked.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mean_keys_checked = if trial_count > 0 {
+        keys_checked.iter().sum::<f64>() / trial_count as f64
+    } else {
+        0.0
+    };
+    let median_keys_checked = percentile(&keys_checked, 0.5);
+    let p95_keys_checked = percentile(&keys_checked, 0.95);
+
+    let total_wall_time: f64 = trials.iter().map(|t| t.wall_time_secs).sum();
+    let mean_wall_time_secs = if trial_count > 0 {
+        total_wall_time / trial_count as f64
+    } else {
+        0.0
+    };
+    let total_keys_checked: u64 = trials.iter().map(|t| t.keys_checked).sum();
+    let keys_per_second = if total_wall_time > 0.0 {
+        total_keys_checked as f64 / total_wall_time
+    } else {
+        0.0
+    };
+
+    AlgorithmStats {
+        algorithm: algorithm.to_string(),
+        bits,
+        trials: trial_count,
+        success_rate: if trial_count > 0 {
+            successes as f64 / trial_count as f64
+        } else {
+            0.0
+        },
+        mean_keys_checked,
+        median_keys_checked,
+        p95_keys_checked,
+        mean_wall_time_secs,
+        keys_per_second,
+    }
+}
+
+/// A full comparison run: every `TrialResult` plus the per-(algorithm, bits)
+/// `AlgorithmStats` summarizing them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub key_budget: u64,
+    pub trials: Vec<TrialResult>,
+    pub stats: Vec<AlgorithmStats>,
+}
+
+/// Runs `trials` planted-puzzle trials of both algorithms at each bit width
+/// in `bits_list`, bounded by `key_budget` keys per trial, and returns the
+/// aggregate `Report`. `seed` makes the planted keys and random walk runs
+/// reproducible; `None` seeds from OS entropy.
+pub fn run_comparison(bits_list: &[u32], trials: usize, key_budget: u64, seed: Option<u64>) -> Result<Report> {
+    use rand::rngs::StdRng;
+
+    let mut rng = match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut all_trials = Vec::new();
+    let mut stats = Vec::new();
+
+    for &bits in bits_list {
+        let mut sequential_trials = Vec::with_capacity(trials);
+        let mut random_walk_trials = Vec::with_capacity(trials);
+
+        for _ in 0..trials {
+            let puzzle = generate_planted_puzzle(bits, &mut rng)?;
+
+            let mut sequential_result = run_sequential_trial(&puzzle, key_budget);
+            sequential_result.bits = bits;
+            sequential_trials.push(sequential_result);
+
+            let walk_seed: u64 = rng.r#gen();
+            let mut random_walk_result = run_random_walk_trial(&puzzle, key_budget, walk_seed);
+            random_walk_result.bits = bits;
+            random_walk_trials.push(random_walk_result);
+        }
+
+        stats.push(summarize("sequential", bits, &sequential_trials));
+        stats.push(summarize("random_walk", bits, &random_walk_trials));
+
+        all_trials.extend(sequential_trials);
+        all_trials.extend(random_walk_trials);
+    }
+
+    Ok(Report {
+        key_budget,
+        trials: all_trials,
+        stats,
+    })
+}
+
+/// Prints `report.stats` as a box-drawn table, matching
+/// `puzzle_data::list_available_puzzles`'s style.
+pub fn print_report_table(report: &Report) {
+    println!("Algorithm comparison (key budget: {} keys/trial):", report.key_budget);
+    println!("┌──────┬─────────────┬────────┬──────────┬──────────────┬──────────────┬──────────────┬────────────┐");
+    println!("│ Bits │ Algorithm   │ Trials │ Success  │ Mean Keys    │ Median Keys  │ P95 Keys     │ Keys/sec   │");
+    println!("├──────┼─────────────┼────────┼──────────┼──────────────┼──────────────┼──────────────┼────────────┤");
+    for stat in &report.stats {
+        println!(
+            "│ {:4} │ {:11} │ {:6} │ {:7.1}% │ {:12.0} │ {:12.0} │ {:12.0} │ {:10.0} │",
+            stat.bits,
+            stat.algorithm,
+            stat.trials,
+            stat.success_rate * 100.0,
+            stat.mean_keys_checked,
+            stat.median_keys_checked,
+            stat.p95_keys_checked,
+            stat.keys_per_second
+        );
+    }
+    println!("└──────┴─────────────┴────────┴──────────┴──────────────┴──────────────┴──────────────┴────────────┘");
+}
+
+pub fn write_report_json(report: &Report, path: &str) -> Result<()> {
+    let json = serde_json::to_string_pretty(report)?;
+    fs::write(path, json)?;
+    println!("Report saved to: {}", path);
+    Ok(())
+}
+
+pub fn write_report_csv(report: &Report, path: &str) -> Result<()> {
+    let mut csv = String::from(
+        "bits,algorithm,trials,success_rate,mean_keys_checked,median_keys_checked,p95_keys_checked,mean_wall_time_secs,keys_per_second\n",
+    );
+    for stat in &report.stats {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            stat.bits,
+            stat.algorithm,
+            stat.trials,
+            stat.success_rate,
+            stat.mean_keys_checked,
+            stat.median_keys_checked,
+            stat.p95_keys_checked,
+            stat.mean_wall_time_secs,
+            stat.keys_per_second
+        ));
+    }
+    fs::write(path, csv)?;
+    println!("Report saved to: {}", path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn test_generate_planted_puzzle_key_is_in_range_and_matches_address() {
+        let mut rng = StepRng::new(0x1234_5678, 0x9E37_79B9_7F4A_7C15);
+        let puzzle = generate_planted_puzzle(20, &mut rng).unwrap();
+
+        let key = BigUint::parse_bytes(puzzle.private_key_hex.as_bytes(), 16).unwrap();
+        assert!(key >= puzzle.range_start);
+        assert!(key <= puzzle.range_end);
+
+        let addresses = private_key_to_addresses(&key).unwrap();
+        assert!(addresses.contains(&puzzle.address));
+    }
+
+    #[test]
+    fn test_sequential_trial_finds_a_small_planted_puzzle() {
+        let mut rng = StepRng::new(0x1, 0x9E37_79B9_7F4A_7C15);
+        let puzzle = generate_planted_puzzle(10, &mut rng).unwrap();
+
+        let result = run_sequential_trial(&puzzle, 10_000);
+        assert!(result.found);
+        assert!(result.keys_checked > 0);
+    }
+
+    #[test]
+    fn test_run_comparison_produces_stats_for_every_bit_width() {
+        let report = run_comparison(&[8, 10], 3, 5_000, Some(42)).unwrap();
+        assert_eq!(report.stats.len(), 4); // 2 bit widths * 2 algorithms
+        assert_eq!(report.trials.len(), 12); // 2 bit widths * 3 trials * 2 algorithms
+        for stat in &report.stats {
+            assert_eq!(stat.trials, 3);
+        }
+    }
+}
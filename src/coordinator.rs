@@ -0,0 +1,622 @@
+use crate::bitcoin_utils::private_key_to_addresses;
+use crate::{distribute_range_to_workers, Worker};
+use anyhow::{anyhow, Result};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A message a worker sends to the coordinator over its control connection.
+/// `Register` doubles as "I'm ready for more work", so a worker re-sends it
+/// after finishing a subrange instead of needing a separate request message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum WorkerMessage {
+    Register {
+        name: String,
+        hashes_per_second: u64,
+    },
+    Heartbeat {
+        subrange_id: u64,
+        keys_checked: u64,
+    },
+    SubrangeComplete {
+        subrange_id: u64,
+    },
+    Found {
+        subrange_id: u64,
+        private_key_hex: String,
+        address: String,
+    },
+}
+
+/// A message the coordinator sends back to a worker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CoordinatorMessage {
+    Assign {
+        subrange_id: u64,
+        start_hex: String,
+        end_hex: String,
+    },
+    AllWorkAssigned,
+    Stop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SubrangeStatus {
+    Assigned,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubrangeRecord {
+    pub id: u64,
+    pub worker_name: String,
+    pub hashes_per_second: u64,
+    pub start_hex: String,
+    pub end_hex: String,
+    pub status: SubrangeStatus,
+    pub assigned_unix_secs: u64,
+    pub last_heartbeat_unix_secs: u64,
+}
+
+/// Persisted coordinator state: the global range/targets, the cursor marking
+/// what hasn't been handed out yet, and every subrange ever assigned, so a
+/// killed coordinator can reload the whole cluster's progress instead of
+/// starting the range over (mirrors `checkpoint::SequentialCheckpoint`'s
+/// match-then-resume pattern for the single-machine case).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinatorLedger {
+    pub range_start_hex: String,
+    pub range_end_hex: String,
+    pub targets: Vec<String>,
+    pub next_cursor_hex: String,
+    pub records: Vec<SubrangeRecord>,
+    pub found: Option<(String, String)>,
+}
+
+impl CoordinatorLedger {
+    fn new(range_start: &BigUint, range_end: &BigUint, targets: &HashSet<String>) -> Self {
+        CoordinatorLedger {
+            range_start_hex: format!("{:x}", range_start),
+            range_end_hex: format!("{:x}", range_end),
+            targets: targets.iter().cloned().collect(),
+            next_cursor_hex: format!("{:x}", range_start),
+            records: Vec::new(),
+            found: None,
+        }
+    }
+
+    fn load_or_new(
+        path: Option<&str>,
+        range_start: &BigUint,
+        range_end: &BigUint,
+        targets: &HashSet<String>,
+    ) -> Result<Self> {
+        if let Some(path) = path {
+            if Path::new(path).exists() {
+                let contents = fs::read_to_string(path)?;
+                let ledger: CoordinatorLedger = serde_json::from_str(&contents)?;
+                if ledger.range_start_hex == format!("{:x}", range_start)
+                    && ledger.range_end_hex == format!("{:x}", range_end)
+                {
+                    println!(
+                        "📂 Resuming coordinator from ledger '{}' ({} subranges recorded)",
+                        path,
+                        ledger.records.len()
+                    );
+                    return Ok(ledger);
+                }
+                println!("⚠️  Ledger '{}' doesn't match this range, starting fresh", path);
+            }
+        }
+        Ok(CoordinatorLedger::new(range_start, range_end, targets))
+    }
+
+    fn save(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+fn unix_secs_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+struct CoordinatorState {
+    ledger: CoordinatorLedger,
+    pending_reassignment: VecDeque<(String, String)>,
+    next_id: u64,
+    target_time_minutes: f64,
+    range_end: BigUint,
+    ledger_path: Option<String>,
+}
+
+impl CoordinatorState {
+    /// Hands out the next pending-reassignment subrange if one is waiting
+    /// (a prior worker timed out), otherwise carves a fresh one off the
+    /// cursor sized by the existing `distribute_range_to_workers` math.
+    fn assign_subrange(&mut self, worker_name: &str, hashes_per_second: u64) -> Option<SubrangeRecord> {
+        let (start_hex, end_hex) = if let Some(range) = self.pending_reassignment.pop_front() {
+            range
+        } else {
+            let cursor = BigUint::parse_bytes(self.ledger.next_cursor_hex.as_bytes(), 16)?;
+            if cursor > self.range_end {
+                return None;
+            }
+            let worker = Worker {
+                name: worker_name.to_string(),
+                hashes_per_second,
+            };
+            let (ranges, _remaining) = distribute_range_to_workers(
+                vec![worker],
+                &cursor,
+                &self.range_end,
+                self.target_time_minutes,
+            )
+            .ok()?;
+            let range = ranges.into_iter().next()?;
+            let next = BigUint::parse_bytes(range.end_hex.as_bytes(), 16)? + 1u32;
+            self.ledger.next_cursor_hex = format!("{:x}", next);
+            (range.start_hex, range.end_hex)
+        };
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let now = unix_secs_now();
+        let record = SubrangeRecord {
+            id,
+            worker_name: worker_name.to_string(),
+            hashes_per_second,
+            start_hex,
+            end_hex,
+            status: SubrangeStatus::Assigned,
+            assigned_unix_secs: now,
+            last_heartbeat_unix_secs: now,
+        };
+        self.ledger.records.push(record.clone());
+        self.persist();
+        Some(record)
+    }
+
+    fn record_mut(&mut self, id: u64) -> Option<&mut SubrangeRecord> {
+        self.ledger.records.iter_mut().find(|r| r.id == id)
+    }
+
+    fn heartbeat(&mut self, id: u64) {
+        let now = unix_secs_now();
+        if let Some(record) = self.record_mut(id) {
+            record.last_heartbeat_unix_secs = now;
+        }
+    }
+
+    fn complete(&mut self, id: u64) {
+        if let Some(record) = self.record_mut(id) {
+            record.status = SubrangeStatus::Completed;
+        }
+        self.persist();
+    }
+
+    fn found(&mut self, subrange_id: u64, private_key_hex: String, address: String) {
+        if let Some(record) = self.record_mut(subrange_id) {
+            record.status = SubrangeStatus::Completed;
+        }
+        self.ledger.found = Some((private_key_hex, address));
+        self.persist();
+    }
+
+    /// Marks any subrange that hasn't heartbeat-ed in `timeout_secs` as
+    /// failed and queues its range for reassignment to the next worker that
+    /// registers.
+    fn reap_timed_out(&mut self, timeout_secs: u64) {
+        let now = unix_secs_now();
+        let mut reassign = Vec::new();
+        for record in self.ledger.records.iter_mut() {
+            if record.status == SubrangeStatus::Assigned
+                && now.saturating_sub(record.last_heartbeat_unix_secs) > timeout_secs
+            {
+                record.status = SubrangeStatus::Failed;
+                reassign.push((record.start_hex.clone(), record.end_hex.clone()));
+            }
+        }
+        if !reassign.is_empty() {
+            for (start_hex, end_hex) in &reassign {
+                println!(
+                    "⏱️  Worker '{}' timed out, reassigning subrange 0x{} – 0x{}",
+                    self.ledger
+                        .records
+                        .iter()
+                        .find(|r| &r.start_hex == start_hex && &r.end_hex == end_hex)
+                        .map(|r| r.worker_name.as_str())
+                        .unwrap_or("unknown"),
+                    start_hex,
+                    end_hex
+                );
+            }
+            self.pending_reassignment.extend(reassign);
+            self.persist();
+        }
+    }
+
+    fn persist(&self) {
+        if let Some(path) = &self.ledger_path {
+            if let Err(e) = self.ledger.save(path) {
+                println!("⚠️  Failed to save ledger '{}': {}", path, e);
+            }
+        }
+    }
+}
+
+fn send_coordinator_message(writer: &mut TcpStream, message: &CoordinatorMessage) -> Result<()> {
+    let mut json = serde_json::to_string(message)?;
+    json.push('\n');
+    writer.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+fn send_worker_message(writer: &mut TcpStream, message: &WorkerMessage) -> Result<()> {
+    let mut json = serde_json::to_string(message)?;
+    json.push('\n');
+    writer.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// Runs the coordinator server: accepts worker connections, assigns
+/// subranges sized by `distribute_range_to_workers`, tracks heartbeats, and
+/// reassigns subranges from workers that time out or disconnect. Once a
+/// match is reported, the coordinator answers each worker's next message (a
+/// mid-scan heartbeat or a `Register`) with `Stop`, which ends that worker's
+/// scan even mid-subrange; nothing is pushed to a worker until it sends a
+/// message.
+pub fn run_coordinator(
+    bind_addr: &str,
+    range_start: &BigUint,
+    range_end: &BigUint,
+    targets: HashSet<String>,
+    target_time_minutes: f64,
+    ledger_path: Option<String>,
+    heartbeat_timeout_secs: u64,
+) -> Result<()> {
+    let ledger = CoordinatorLedger::load_or_new(ledger_path.as_deref(), range_start, range_end, &targets)?;
+    let next_id = ledger.records.iter().map(|r| r.id + 1).max().unwrap_or(0);
+    let state = Arc::new(Mutex::new(CoordinatorState {
+        ledger,
+        pending_reassignment: VecDeque::new(),
+        next_id,
+        target_time_minutes,
+        range_end: range_end.clone(),
+        ledger_path,
+    }));
+
+    let listener = TcpListener::bind(bind_addr)?;
+    println!("🛰️  Coordinator listening on {}", bind_addr);
+
+    let reaper_state = state.clone();
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(5));
+        reaper_state.lock().unwrap().reap_timed_out(heartbeat_timeout_secs);
+    });
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let state = state.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, state) {
+                println!("⚠️  Connection error: {}", e);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, state: Arc<Mutex<CoordinatorState>>) -> Result<()> {
+    let mut writer = stream.try_clone()?;
+    let reader = BufReader::new(stream);
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message: WorkerMessage = serde_json::from_str(&line)?;
+
+        if state.lock().unwrap().ledger.found.is_some() {
+            send_coordinator_message(&mut writer, &CoordinatorMessage::Stop)?;
+            break;
+        }
+
+        match message {
+            WorkerMessage::Register {
+                name,
+                hashes_per_second,
+            } => {
+                let assignment = state.lock().unwrap().assign_subrange(&name, hashes_per_second);
+                match assignment {
+                    Some(record) => {
+                        println!(
+                            "📋 Assigned worker '{}' subrange 0x{} – 0x{}",
+                            record.worker_name, record.start_hex, record.end_hex
+                        );
+                        send_coordinator_message(
+                            &mut writer,
+                            &CoordinatorMessage::Assign {
+                                subrange_id: record.id,
+                                start_hex: record.start_hex,
+                                end_hex: record.end_hex,
+                            },
+                        )?;
+                    }
+                    None => send_coordinator_message(&mut writer, &CoordinatorMessage::AllWorkAssigned)?,
+                }
+            }
+            WorkerMessage::Heartbeat { subrange_id, .. } => {
+                state.lock().unwrap().heartbeat(subrange_id);
+            }
+            WorkerMessage::SubrangeComplete { subrange_id } => {
+                state.lock().unwrap().complete(subrange_id);
+            }
+            WorkerMessage::Found {
+                subrange_id,
+                private_key_hex,
+                address,
+            } => {
+                println!(
+                    "🎉 MATCH FOUND by worker! Private Key: {} Address: {}",
+                    private_key_hex, address
+                );
+                state.lock().unwrap().found(subrange_id, private_key_hex, address);
+                send_coordinator_message(&mut writer, &CoordinatorMessage::Stop)?;
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a worker: registers with the coordinator, scans whatever subrange
+/// it's assigned, and keeps asking for more until the range is exhausted or
+/// the coordinator signals `Stop`.
+pub fn run_worker(
+    connect_addr: &str,
+    worker_name: String,
+    hashes_per_second: u64,
+    targets: HashSet<String>,
+) -> Result<()> {
+    loop {
+        let stream = TcpStream::connect(connect_addr)?;
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+
+        send_worker_message(
+            &mut writer,
+            &WorkerMessage::Register {
+                name: worker_name.clone(),
+                hashes_per_second,
+            },
+        )?;
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let message: CoordinatorMessage = serde_json::from_str(line.trim())?;
+
+        match message {
+            CoordinatorMessage::Assign {
+                subrange_id,
+                start_hex,
+                end_hex,
+            } => {
+                println!("📥 Assigned subrange 0x{} – 0x{}", start_hex, end_hex);
+
+                // Hand the connection's read half to a background thread so a
+                // mid-scan `Stop` (another worker found the key) flips `stop`
+                // right away, instead of only being noticed on the next
+                // `Register` once this subrange finishes.
+                let stop = Arc::new(AtomicBool::new(false));
+                let reader_stop = stop.clone();
+                thread::spawn(move || {
+                    for line in reader.lines() {
+                        let line = match line {
+                            Ok(line) => line,
+                            Err(_) => break,
+                        };
+                        if line.trim().is_empty() {
+                            continue;
+                        }
+                        if let Ok(CoordinatorMessage::Stop) = serde_json::from_str(&line) {
+                            reader_stop.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                });
+
+                match scan_subrange(&mut writer, subrange_id, &start_hex, &end_hex, &targets, &stop)? {
+                    ScanOutcome::Found(private_key, address) => {
+                        println!("🎉 MATCH FOUND!");
+                        println!("Private Key: {}", private_key);
+                        println!("Address: {}", address);
+                        return Ok(());
+                    }
+                    ScanOutcome::StoppedByCoordinator => {
+                        println!("🛑 Coordinator signaled stop mid-scan (match found elsewhere)");
+                        return Ok(());
+                    }
+                    ScanOutcome::Exhausted => {
+                        println!("✅ Subrange exhausted, requesting more work...");
+                    }
+                }
+            }
+            CoordinatorMessage::AllWorkAssigned => {
+                println!("✅ No more work available — range fully assigned");
+                return Ok(());
+            }
+            CoordinatorMessage::Stop => {
+                println!("🛑 Coordinator signaled stop (match found elsewhere)");
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Outcome of scanning one assigned subrange.
+enum ScanOutcome {
+    Found(String, String),
+    Exhausted,
+    StoppedByCoordinator,
+}
+
+fn scan_subrange(
+    writer: &mut TcpStream,
+    subrange_id: u64,
+    start_hex: &str,
+    end_hex: &str,
+    targets: &HashSet<String>,
+    stop: &Arc<AtomicBool>,
+) -> Result<ScanOutcome> {
+    let start = BigUint::parse_bytes(start_hex.as_bytes(), 16)
+        .ok_or_else(|| anyhow!("Coordinator sent an invalid start_hex '{}'", start_hex))?;
+    let end = BigUint::parse_bytes(end_hex.as_bytes(), 16)
+        .ok_or_else(|| anyhow!("Coordinator sent an invalid end_hex '{}'", end_hex))?;
+
+    let mut current = start;
+    let mut local_count = 0u64;
+    let mut total_count = 0u64;
+
+    while current <= end && !stop.load(Ordering::Relaxed) {
+        if let Ok(addresses) = private_key_to_addresses(&current) {
+            for address in addresses {
+                if targets.contains(&address) {
+                    let hex_key = format!("{:064x}", current);
+                    send_worker_message(
+                        writer,
+                        &WorkerMessage::Found {
+                            subrange_id,
+                            private_key_hex: hex_key.clone(),
+                            address: address.clone(),
+                        },
+                    )?;
+                    return Ok(ScanOutcome::Found(hex_key, address));
+                }
+            }
+        }
+
+        current += 1u32;
+        local_count += 1;
+        total_count += 1;
+
+        if local_count % 10000 == 0 {
+            send_worker_message(
+                writer,
+                &WorkerMessage::Heartbeat {
+                    subrange_id,
+                    keys_checked: total_count,
+                },
+            )?;
+            local_count = 0;
+        }
+    }
+
+    if stop.load(Ordering::Relaxed) {
+        return Ok(ScanOutcome::StoppedByCoordinator);
+    }
+
+    send_worker_message(writer, &WorkerMessage::SubrangeComplete { subrange_id })?;
+    Ok(ScanOutcome::Exhausted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_subrange_sizes_from_distribute_range_to_workers() {
+        let range_start = BigUint::from(1u32);
+        let range_end = BigUint::from(1_000_000u32);
+        let targets: HashSet<String> = HashSet::new();
+        let ledger = CoordinatorLedger::new(&range_start, &range_end, &targets);
+
+        let mut state = CoordinatorState {
+            ledger,
+            pending_reassignment: VecDeque::new(),
+            next_id: 0,
+            target_time_minutes: 1.0,
+            range_end: range_end.clone(),
+            ledger_path: None,
+        };
+
+        let record = state.assign_subrange("worker-1", 10_000).unwrap();
+        assert_eq!(record.id, 0);
+        assert_eq!(record.start_hex, format!("{:x}", range_start));
+        // 10_000 keys/sec * 60 sec = 600_000 keys
+        let expected_end = &range_start + 600_000u32 - 1u32;
+        assert_eq!(record.end_hex, format!("{:x}", expected_end));
+
+        let next_record = state.assign_subrange("worker-2", 10_000).unwrap();
+        assert_eq!(next_record.id, 1);
+        assert_eq!(next_record.start_hex, format!("{:x}", expected_end + 1u32));
+    }
+
+    #[test]
+    fn test_reap_timed_out_requeues_stale_assignment() {
+        let range_start = BigUint::from(1u32);
+        let range_end = BigUint::from(1_000_000u32);
+        let targets: HashSet<String> = HashSet::new();
+        let ledger = CoordinatorLedger::new(&range_start, &range_end, &targets);
+
+        let mut state = CoordinatorState {
+            ledger,
+            pending_reassignment: VecDeque::new(),
+            next_id: 0,
+            target_time_minutes: 1.0,
+            range_end,
+            ledger_path: None,
+        };
+        let record = state.assign_subrange("worker-1", 10_000).unwrap();
+        state.record_mut(record.id).unwrap().last_heartbeat_unix_secs = 0;
+
+        state.reap_timed_out(0);
+
+        assert_eq!(state.pending_reassignment.len(), 1);
+        assert_eq!(
+            state.record_mut(record.id).unwrap().status,
+            SubrangeStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_assign_subrange_returns_none_once_range_exhausted() {
+        let range_start = BigUint::from(1u32);
+        let range_end = BigUint::from(100u32);
+        let targets: HashSet<String> = HashSet::new();
+        let ledger = CoordinatorLedger::new(&range_start, &range_end, &targets);
+
+        let mut state = CoordinatorState {
+            ledger,
+            pending_reassignment: VecDeque::new(),
+            next_id: 0,
+            target_time_minutes: 1.0,
+            range_end,
+            ledger_path: None,
+        };
+
+        // One huge subrange consumes the whole (tiny) range immediately.
+        let first = state.assign_subrange("worker-1", 10_000);
+        assert!(first.is_some());
+        let second = state.assign_subrange("worker-2", 10_000);
+        assert!(second.is_none());
+    }
+}
@@ -1,4 +1,8 @@
 pub mod bitcoin_utils;
+pub mod bloom;
+pub mod checkpoint;
+pub mod compare;
+pub mod coordinator;
 pub mod puzzle_data;
 pub mod random_walk;
 
@@ -301,6 +305,42 @@ pub fn calculate_walk_parameters(hashes_per_second: u64) -> (usize, usize, usize
     (walk_iterations, walk_count, adapt_interval)
 }
 
+/// Duration of the deterministic calibration micro-benchmark.
+const CALIBRATION_DURATION: Duration = Duration::from_secs(3);
+
+/// Benchmarks this machine's real `private_key_to_addresses` throughput using
+/// a deterministic `rand::rngs::mock::StepRng`-driven key stream on one
+/// thread (instead of `benchmark_hashes_per_second`'s multi-threaded run), so
+/// every run tests the same key sequence, then feeds the
+/// measured rate into `calculate_walk_parameters`. This gives users real
+/// machine-specific parameters instead of picking the closest hand-labeled
+/// tier from a guessed table.
+pub fn calibrate_walk_parameters() -> (usize, usize, usize) {
+    use crate::bitcoin_utils::private_key_to_addresses;
+    use rand::rngs::mock::StepRng;
+    use rand::RngCore;
+
+    // Fixed seed and increment: every run walks the same sequence of keys.
+    let mut rng = StepRng::new(0x1000_0000_0000_0000, 0x9E37_79B9_7F4A_7C15);
+    let start = Instant::now();
+    let mut keys_tested: u64 = 0;
+
+    while start.elapsed() < CALIBRATION_DURATION {
+        let key = BigUint::from(rng.next_u64());
+        if private_key_to_addresses(&key).is_ok() {
+            keys_tested += 1;
+        }
+    }
+
+    let elapsed_secs = start.elapsed().as_secs_f64().max(f64::EPSILON);
+    let hashes_per_second = (keys_tested as f64 / elapsed_secs) as u64;
+
+    println!("🧮 Calibrating random walk parameters via deterministic micro-benchmark...");
+    println!("   Measured throughput: {} keys/second (deterministic key stream)", hashes_per_second);
+
+    calculate_walk_parameters(hashes_per_second)
+}
+
 /// Calculate optimal random walk parameters for a machine and display them
 pub fn calculate_and_display_walk_parameters(hashes_per_second: u64) -> (usize, usize, usize) {
     let (iterations, walks, adapt) = calculate_walk_parameters(hashes_per_second);
@@ -425,4 +465,12 @@ mod tests {
         assert!(adapt >= 100);
         assert!(walks <= 32); // Should be capped
     }
+
+    #[test]
+    fn test_calibrate_walk_parameters() {
+        let (iter, walks, adapt) = calibrate_walk_parameters();
+        assert!(iter >= 1000);
+        assert!(walks >= 2);
+        assert!(adapt >= 100);
+    }
 }
\ No newline at end of file
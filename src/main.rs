@@ -7,19 +7,31 @@ use rayon::prelude::*;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::time::Instant;
+use std::thread;
+use std::time::{Duration, Instant};
 
 mod bitcoin_utils;
+mod bloom;
+mod checkpoint;
 mod puzzle_data;
 mod random_walk;
-use bitcoin_utils::{parse_hex_key, private_key_to_addresses};
+use bitcoin_utils::{
+    address_to_hash160, parse_hash160_hex, parse_hex_key, private_key_to_addresses,
+    private_key_to_hash160s,
+};
+use bloom::Hash160BloomFilter;
+use checkpoint::{RandomWalkCheckpoint, SequentialCheckpoint};
 use puzzle_data::{get_easiest_puzzles, get_puzzle_by_number, list_available_puzzles};
-use random_walk::parallel_adaptive_random_walk_search_with_progress;
+use random_walk::{
+    parallel_adaptive_random_walk_search_with_progress, LevyParams, RngBackend, WalkState,
+};
 
 use btc_puzzle_hunter::{
     Worker, benchmark_hashes_per_second, distribute_range_to_workers, print_worker_distribution,
     save_worker_distribution_to_file, calculate_and_display_walk_parameters,
 };
+use btc_puzzle_hunter::coordinator::{run_coordinator, run_worker};
+use btc_puzzle_hunter::compare::{print_report_table, run_comparison, write_report_csv, write_report_json};
 
 #[cfg(test)]
 mod tests {
@@ -194,6 +206,127 @@ fn main() -> Result<()> {
                 .help("Duration for benchmark test in seconds")
                 .default_value("10"),
         )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .value_name("NUMBER")
+                .help("Seed for reproducible runs: the random walk RNG in random-walk mode, or the planted keys and walks in --compare mode"),
+        )
+        .arg(
+            Arg::new("rng-backend")
+                .long("rng-backend")
+                .value_name("pcg64|chacha20")
+                .help("RNG backend for the random walk: pcg64 for raw throughput, chacha20 for stream quality")
+                .default_value("chacha20"),
+        )
+        .arg(
+            Arg::new("reseed-after-mb")
+                .long("reseed-after-mb")
+                .value_name("MB")
+                .help("Reseed the random walk RNG from OS entropy after this many megabytes drawn (for multi-hour runs)"),
+        )
+        .arg(
+            Arg::new("targets-file")
+                .long("targets-file")
+                .value_name("FILE")
+                .help("Load a large target list (one address or hash160 hex per line) and match via a Bloom filter instead of --targets (sequential search only)"),
+        )
+        .arg(
+            Arg::new("fp-rate")
+                .long("fp-rate")
+                .value_name("RATE")
+                .help("Target false positive rate for the --targets-file Bloom filter")
+                .default_value("0.000001"),
+        )
+        .arg(
+            Arg::new("max-time")
+                .long("max-time")
+                .value_name("SECONDS")
+                .help("Stop gracefully after this many seconds, saving a checkpoint if --checkpoint is set"),
+        )
+        .arg(
+            Arg::new("checkpoint")
+                .long("checkpoint")
+                .value_name("FILE")
+                .help("Save/resume search progress to this file on stop (Ctrl-C or --max-time)"),
+        )
+        .arg(
+            Arg::new("coordinate")
+                .long("coordinate")
+                .value_name("HOST:PORT")
+                .help("Run as a coordinator server, handing out subranges of --start/--end to connecting workers"),
+        )
+        .arg(
+            Arg::new("connect")
+                .long("connect")
+                .value_name("HOST:PORT")
+                .help("Run as a worker: connect to a --coordinate server and search the subranges it assigns"),
+        )
+        .arg(
+            Arg::new("worker-name")
+                .long("worker-name")
+                .value_name("NAME")
+                .help("Name this worker reports to the coordinator (defaults to hostname)"),
+        )
+        .arg(
+            Arg::new("target-time-minutes")
+                .long("target-time-minutes")
+                .value_name("MINUTES")
+                .help("Coordinator: target minutes of work per subrange handed to a worker")
+                .default_value("10"),
+        )
+        .arg(
+            Arg::new("ledger")
+                .long("ledger")
+                .value_name("FILE")
+                .help("Coordinator: persist the subrange assignment ledger to this file so it can resume"),
+        )
+        .arg(
+            Arg::new("heartbeat-timeout")
+                .long("heartbeat-timeout")
+                .value_name("SECONDS")
+                .help("Coordinator: reassign a subrange if its worker hasn't sent a heartbeat in this long")
+                .default_value("120"),
+        )
+        .arg(
+            Arg::new("compare")
+                .long("compare")
+                .help("Benchmark sequential search against random walk on synthetic planted puzzles and report the results")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("bits")
+                .long("bits")
+                .value_name("BITS")
+                .help("Comma-separated bit widths to sweep for --compare")
+                .default_value("20,24,28"),
+        )
+        .arg(
+            Arg::new("trials")
+                .long("trials")
+                .value_name("COUNT")
+                .help("Number of planted-puzzle trials per bit width for --compare")
+                .default_value("20"),
+        )
+        .arg(
+            Arg::new("key-budget")
+                .long("key-budget")
+                .value_name("KEYS")
+                .help("Per-trial key budget for --compare: a trial that hasn't found its planted key after this many keys counts as a miss")
+                .default_value("200000"),
+        )
+        .arg(
+            Arg::new("report-json")
+                .long("report-json")
+                .value_name("FILE")
+                .help("--compare: also write the report as JSON to this file"),
+        )
+        .arg(
+            Arg::new("report-csv")
+                .long("report-csv")
+                .value_name("FILE")
+                .help("--compare: also write the report as CSV to this file"),
+        )
         .get_matches();
 
     // Handle benchmark command
@@ -245,12 +378,48 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Handle compare command
+    if matches.get_flag("compare") {
+        let bits_list: Vec<u32> = matches
+            .get_one::<String>("bits")
+            .unwrap()
+            .split(',')
+            .map(|s| s.trim().parse())
+            .collect::<std::result::Result<_, _>>()?;
+        let trials: usize = matches.get_one::<String>("trials").unwrap().parse()?;
+        let key_budget: u64 = matches.get_one::<String>("key-budget").unwrap().parse()?;
+        let seed: Option<u64> = matches
+            .get_one::<String>("seed")
+            .map(|s| s.parse())
+            .transpose()?;
+
+        println!(
+            "Comparing sequential search vs. random walk across bits={:?}, {} trials/width, {} keys/trial budget",
+            bits_list, trials, key_budget
+        );
+        let report = run_comparison(&bits_list, trials, key_budget, seed)?;
+        print_report_table(&report);
+
+        if let Some(path) = matches.get_one::<String>("report-json") {
+            write_report_json(&report, path)?;
+        }
+        if let Some(path) = matches.get_one::<String>("report-csv") {
+            write_report_csv(&report, path)?;
+        }
+
+        return Ok(());
+    }
+
     // Handle easy puzzles command - only if no other specific command and no manual range
     if !matches.contains_id("puzzle")
         && !matches.get_flag("list")
         && !matches.contains_id("start")
         && !matches.contains_id("end")
         && !matches.contains_id("targets")
+        && !matches.contains_id("targets-file")
+        && !matches.contains_id("coordinate")
+        && !matches.contains_id("connect")
+        && !matches.get_flag("compare")
     {
         if let Some(easy_count_str) = matches.get_one::<String>("easy") {
             if let Ok(count) = easy_count_str.parse::<usize>() {
@@ -293,14 +462,48 @@ fn main() -> Result<()> {
             let end = matches
                 .get_one::<String>("end")
                 .ok_or_else(|| anyhow::anyhow!("--end is required when not using --puzzle"))?;
-            let targets = matches
-                .get_one::<String>("targets")
-                .ok_or_else(|| anyhow::anyhow!("--targets is required when not using --puzzle"))?;
-            (start.clone(), end.clone(), targets.clone())
+            let targets = match matches.get_one::<String>("targets") {
+                Some(targets) => targets.clone(),
+                None if matches.contains_id("targets-file") => String::new(),
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "--targets or --targets-file is required when not using --puzzle"
+                    ))
+                }
+            };
+            (start.clone(), end.clone(), targets)
         };
     let threads: usize = matches.get_one::<String>("threads").unwrap().parse()?;
     let batch_size: u64 = matches.get_one::<String>("batch-size").unwrap().parse()?;
     let use_random_walk = matches.get_flag("random-walk");
+    let mut seed: Option<u64> = matches
+        .get_one::<String>("seed")
+        .map(|s| s.parse())
+        .transpose()?;
+    let seed_explicit = matches.contains_id("seed");
+    let mut rng_backend = parse_rng_backend(matches.get_one::<String>("rng-backend").unwrap())?;
+    let rng_backend_explicit =
+        matches.value_source("rng-backend") == Some(clap::ValueSource::CommandLine);
+    let reseed_after_bytes: Option<u64> = matches
+        .get_one::<String>("reseed-after-mb")
+        .map(|s| s.parse::<u64>().map(|mb| mb * 1_000_000))
+        .transpose()?;
+    let targets_file = matches.get_one::<String>("targets-file").cloned();
+    let fp_rate: f64 = matches.get_one::<String>("fp-rate").unwrap().parse()?;
+    let max_time_secs: Option<u64> = matches
+        .get_one::<String>("max-time")
+        .map(|s| s.parse())
+        .transpose()?;
+    let checkpoint_path = matches.get_one::<String>("checkpoint").cloned();
+
+    if targets_file.is_some() && use_random_walk {
+        return Err(anyhow::anyhow!(
+            "--targets-file is only supported with sequential search, not --random-walk"
+        ));
+    }
+    if targets_file.is_some() && checkpoint_path.is_some() {
+        println!("⚠️  --checkpoint is not yet supported together with --targets-file; progress won't be saved");
+    }
 
     let start_key = parse_hex_key(&start_hex)?;
     let end_key = parse_hex_key(&end_hex)?;
@@ -314,9 +517,118 @@ fn main() -> Result<()> {
         [targets_str].into_iter().collect()
     };
 
+    if let Some(bind_addr) = matches.get_one::<String>("coordinate") {
+        let target_time_minutes: f64 = matches
+            .get_one::<String>("target-time-minutes")
+            .unwrap()
+            .parse()?;
+        let heartbeat_timeout_secs: u64 = matches
+            .get_one::<String>("heartbeat-timeout")
+            .unwrap()
+            .parse()?;
+        let ledger_path = matches.get_one::<String>("ledger").cloned();
+
+        println!("Starting coordinator on {}", bind_addr);
+        println!("Range: {} to {}", start_hex, end_hex);
+        println!("Target addresses: {:?}", target_addresses);
+
+        run_coordinator(
+            bind_addr,
+            &start_key,
+            &end_key,
+            target_addresses,
+            target_time_minutes,
+            ledger_path,
+            heartbeat_timeout_secs,
+        )?;
+        return Ok(());
+    }
+
+    if let Some(connect_addr) = matches.get_one::<String>("connect") {
+        // start_key/end_key are not used in worker mode: the worker's actual
+        // subranges always come from the coordinator, so only the target
+        // addresses matter here.
+        let hostname = std::env::var("HOSTNAME")
+            .or_else(|_| std::env::var("COMPUTERNAME"))
+            .unwrap_or_else(|_| "worker".to_string());
+        let worker_name = matches
+            .get_one::<String>("worker-name")
+            .cloned()
+            .unwrap_or(hostname);
+        let benchmark_duration: u64 = matches
+            .get_one::<String>("benchmark-duration")
+            .unwrap()
+            .parse()?;
+        let threads: usize = matches.get_one::<String>("threads").unwrap().parse()?;
+        let threads_opt = if threads == 0 { None } else { Some(threads) };
+
+        println!("🔧 Benchmarking this worker before connecting...");
+        let hashes_per_second = benchmark_hashes_per_second(benchmark_duration, threads_opt)?;
+        println!(
+            "Connecting to coordinator at {} as '{}' ({} keys/sec)",
+            connect_addr, worker_name, hashes_per_second
+        );
+
+        run_worker(connect_addr, worker_name, hashes_per_second, target_addresses)?;
+        return Ok(());
+    }
+
+    let bloom_targets = targets_file
+        .as_ref()
+        .map(|path| load_targets_file(path, fp_rate))
+        .transpose()?;
+
+    // Resume from a matching checkpoint, if one was requested and exists.
+    let mut resume_start_key: Option<BigUint> = None;
+    let mut resume_walk_states: Option<Vec<WalkState>> = None;
+    if let (Some(path), None) = (&checkpoint_path, &targets_file) {
+        if use_random_walk {
+            if let Some(checkpoint) =
+                RandomWalkCheckpoint::load_if_matching(path, &start_hex, &end_hex, &target_addresses)?
+            {
+                println!(
+                    "📂 Resuming random walk from checkpoint '{}' ({} keys already checked)",
+                    path, checkpoint.keys_checked
+                );
+
+                // A resume must re-seed each walk exactly as the checkpoint
+                // describes; only error out if the user explicitly asked for
+                // different RNG settings instead of silently overriding them.
+                if seed_explicit && seed != checkpoint.seed {
+                    return Err(anyhow::anyhow!(
+                        "--seed {:?} conflicts with checkpoint '{}', which was seeded with {:?}; drop --seed to resume with the checkpoint's seed",
+                        seed, path, checkpoint.seed
+                    ));
+                }
+                let checkpoint_rng_backend = parse_rng_backend(&checkpoint.rng_backend)?;
+                if rng_backend_explicit && rng_backend != checkpoint_rng_backend {
+                    return Err(anyhow::anyhow!(
+                        "--rng-backend {} conflicts with checkpoint '{}', which used {}; drop --rng-backend to resume with the checkpoint's backend",
+                        rng_backend_to_str(rng_backend), path, checkpoint.rng_backend
+                    ));
+                }
+                seed = checkpoint.seed;
+                rng_backend = checkpoint_rng_backend;
+
+                resume_walk_states = Some(checkpoint.walk_states);
+            }
+        } else if let Some(checkpoint) =
+            SequentialCheckpoint::load_if_matching(path, &start_hex, &end_hex, &target_addresses)?
+        {
+            println!(
+                "📂 Resuming sequential search from checkpoint '{}' at key 0x{} ({} keys already checked)",
+                path, checkpoint.next_key_hex, checkpoint.keys_checked
+            );
+            resume_start_key = Some(parse_hex_key(&checkpoint.next_key_hex)?);
+        }
+    }
+
     println!("Starting Bitcoin puzzle hunter");
     println!("Range: {} to {}", start_hex, end_hex);
-    println!("Target addresses: {:?}", target_addresses);
+    match &targets_file {
+        Some(path) => println!("Target file: {} (Bloom filter, fp rate ~{:.1e})", path, fp_rate),
+        None => println!("Target addresses: {:?}", target_addresses),
+    }
     println!(
         "Algorithm: {}",
         if use_random_walk {
@@ -327,6 +639,14 @@ fn main() -> Result<()> {
     );
     if use_random_walk {
         println!("Parameters will be auto-calculated based on machine performance");
+        match seed {
+            Some(s) => println!("RNG seed: {} (reproducible run)", s),
+            None => println!("RNG seed: none (seeded from OS entropy)"),
+        }
+        println!("RNG backend: {}", rng_backend_to_str(rng_backend));
+        if let Some(bytes) = reseed_after_bytes {
+            println!("Reseeding from OS entropy every {} MB drawn", bytes / 1_000_000);
+        }
     } else {
         println!(
             "Threads: {}",
@@ -349,6 +669,27 @@ fn main() -> Result<()> {
     let found = Arc::new(AtomicBool::new(false));
     let keys_checked = Arc::new(AtomicU64::new(0));
 
+    // Ctrl-C flips the same stop flag a natural match does, so the search
+    // winds down gracefully (and a checkpoint is saved below) instead of
+    // being killed mid-batch.
+    {
+        let stop_on_ctrlc = found.clone();
+        ctrlc::set_handler(move || {
+            println!("\n🛑 Caught Ctrl-C, stopping and saving a checkpoint if configured...");
+            stop_on_ctrlc.store(true, Ordering::Relaxed);
+        })?;
+    }
+
+    // --max-time trips the same flag once the budget runs out.
+    if let Some(max_time_secs) = max_time_secs {
+        let stop_on_timeout = found.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(max_time_secs));
+            stop_on_timeout.store(true, Ordering::Relaxed);
+        });
+        println!("Time budget: {} seconds", max_time_secs);
+    }
+
     // Create progress bar based on algorithm type
     let total_keys = &end_key - &start_key + 1u32;
     let progress_bar = if use_random_walk {
@@ -373,37 +714,63 @@ fn main() -> Result<()> {
 
     let start_time = Instant::now();
 
+    let mut walk_states_for_checkpoint: Option<Vec<WalkState>> = None;
+    let progress_positions: Arc<std::sync::Mutex<std::collections::BTreeMap<usize, BigUint>>> =
+        Arc::new(std::sync::Mutex::new(std::collections::BTreeMap::new()));
+    let original_start_key = start_key.clone();
+
     let result = if use_random_walk {
         println!("Using Adaptive Random Walk algorithm...");
-        
+
         // Auto-benchmark machine to determine optimal parameters
         println!("🔧 Auto-detecting optimal random walk parameters...");
         let threads_for_benchmark = if threads == 0 { None } else { Some(threads) };
         let machine_performance = benchmark_hashes_per_second(3, threads_for_benchmark)?; // Quick 3-second benchmark
-        
+
         // Calculate optimal parameters based on machine performance
-        let (walk_iterations, walk_count, adapt_interval) = 
+        let (walk_iterations, walk_count, adapt_interval) =
             calculate_and_display_walk_parameters(machine_performance);
-        
-        parallel_adaptive_random_walk_search_with_progress(
+
+        let (walk_found, walk_states) = parallel_adaptive_random_walk_search_with_progress(
             &start_key,
             &end_key,
             &target_addresses,
             walk_iterations,
             walk_count,
             adapt_interval,
+            seed,
+            rng_backend,
+            reseed_after_bytes,
+            LevyParams::default(),
             Some(progress_bar.clone()),
             Some(keys_checked.clone()),
+            found.clone(),
+            resume_walk_states,
+        )?;
+        walk_states_for_checkpoint = Some(walk_states);
+        Ok(walk_found)
+    } else if let Some((bloom, exact_hash160s)) = &bloom_targets {
+        search_range_bloom(
+            start_key,
+            end_key,
+            bloom,
+            exact_hash160s,
+            batch_size,
+            found.clone(),
+            keys_checked.clone(),
+            progress_bar.clone(),
         )
     } else {
+        let effective_start = resume_start_key.clone().unwrap_or_else(|| start_key.clone());
         search_range(
-            start_key,
+            effective_start,
             end_key,
-            target_addresses,
+            target_addresses.clone(),
             batch_size,
             found.clone(),
             keys_checked.clone(),
             progress_bar.clone(),
+            Some(progress_positions.clone()),
         )
     };
 
@@ -421,7 +788,8 @@ fn main() -> Result<()> {
         println!("Adaptive Random Walk algorithm completed with auto-calculated parameters");
     }
 
-    match result {
+    let match_found = matches!(result, Ok(Some(_)));
+    match &result {
         Ok(Some((private_key, address))) => {
             println!("🎉 MATCH FOUND!");
             println!("Private Key: {}", private_key);
@@ -435,9 +803,72 @@ fn main() -> Result<()> {
         }
     }
 
+    // Save (or clear) the checkpoint now that the search has stopped, unless
+    // it was never requested or isn't supported for this run (see the
+    // --targets-file warning above).
+    if let (Some(path), None) = (&checkpoint_path, &targets_file) {
+        if match_found {
+            let _ = std::fs::remove_file(path);
+        } else if use_random_walk {
+            if let Some(walk_states) = walk_states_for_checkpoint {
+                let checkpoint = RandomWalkCheckpoint {
+                    start_hex: start_hex.clone(),
+                    end_hex: end_hex.clone(),
+                    targets: target_addresses.iter().cloned().collect(),
+                    seed,
+                    rng_backend: rng_backend_to_str(rng_backend).to_string(),
+                    keys_checked: total_checked,
+                    walk_states,
+                };
+                checkpoint.save(path)?;
+                println!("💾 Saved checkpoint to '{}'", path);
+            }
+        } else {
+            let next_key = progress_positions
+                .lock()
+                .unwrap()
+                .values()
+                .min()
+                .cloned()
+                .unwrap_or(original_start_key);
+            let checkpoint = SequentialCheckpoint {
+                start_hex: start_hex.clone(),
+                end_hex: end_hex.clone(),
+                next_key_hex: format!("{:x}", next_key),
+                keys_checked: total_checked,
+                targets: target_addresses.iter().cloned().collect(),
+            };
+            checkpoint.save(path)?;
+            println!("💾 Saved checkpoint to '{}'", path);
+        }
+    }
+
     Ok(())
 }
 
+fn parse_rng_backend(value: &str) -> Result<RngBackend> {
+    match value {
+        "pcg64" => Ok(RngBackend::Pcg64),
+        "chacha20" => Ok(RngBackend::ChaCha20),
+        other => Err(anyhow::anyhow!(
+            "Unknown --rng-backend '{}', expected pcg64 or chacha20",
+            other
+        )),
+    }
+}
+
+fn rng_backend_to_str(backend: RngBackend) -> &'static str {
+    match backend {
+        RngBackend::Pcg64 => "pcg64",
+        RngBackend::ChaCha20 => "chacha20",
+    }
+}
+
+/// Per-batch progress, keyed by batch index, so a stopped sequential search
+/// can compute a safe resume point: the minimum of all batches' last-known
+/// positions never skips a key that wasn't actually checked yet.
+type ProgressPositions = Arc<std::sync::Mutex<std::collections::BTreeMap<usize, BigUint>>>;
+
 fn search_range(
     start: BigUint,
     end: BigUint,
@@ -446,12 +877,26 @@ fn search_range(
     found: Arc<AtomicBool>,
     keys_checked: Arc<AtomicU64>,
     progress_bar: ProgressBar,
+    progress_positions: Option<ProgressPositions>,
 ) -> Result<Option<(String, String)>> {
     let range_size = &end - &start + 1u32;
     let batch_count = (range_size.clone() / batch_size + 1u32)
         .try_into()
         .unwrap_or(usize::MAX);
 
+    // Pre-seed every batch's starting position before rayon schedules any of
+    // them. Otherwise a batch that work-stealing hasn't started yet (e.g. a
+    // match was found and `found` tripped before its turn) has no entry at
+    // all, and a lower-index batch's absence can let `min()` land on a
+    // higher-index batch's progress, skipping the unstarted batch's keys.
+    if let Some(positions) = &progress_positions {
+        let mut positions = positions.lock().unwrap();
+        for batch_idx in 0..batch_count {
+            let batch_start = &start + (batch_idx as u64) * batch_size;
+            positions.entry(batch_idx).or_insert(batch_start);
+        }
+    }
+
     let result = (0..batch_count)
         .into_par_iter()
         .map(|batch_idx| {
@@ -463,12 +908,14 @@ fn search_range(
             let batch_end = std::cmp::min(batch_start.clone() + batch_size - 1u32, end.clone());
 
             search_batch(
+                batch_idx,
                 batch_start,
                 batch_end,
                 &targets,
                 found.clone(),
                 keys_checked.clone(),
                 progress_bar.clone(),
+                progress_positions.clone(),
             )
         })
         .find_map_first(|result| match result {
@@ -487,12 +934,14 @@ fn search_range(
 }
 
 fn search_batch(
+    batch_idx: usize,
     start: BigUint,
     end: BigUint,
     targets: &HashSet<String>,
     found: Arc<AtomicBool>,
     keys_checked: Arc<AtomicU64>,
     progress_bar: ProgressBar,
+    progress_positions: Option<ProgressPositions>,
 ) -> Result<Option<(String, String)>> {
     let mut current = start;
     let mut local_count = 0u64;
@@ -510,6 +959,155 @@ fn search_batch(
         current += 1u32;
         local_count += 1;
 
+        if local_count % 10000 == 0 {
+            keys_checked.fetch_add(local_count, Ordering::Relaxed);
+            progress_bar.inc(local_count);
+            local_count = 0;
+            if let Some(positions) = &progress_positions {
+                positions
+                    .lock()
+                    .unwrap()
+                    .insert(batch_idx, current.clone());
+            }
+        }
+    }
+
+    if local_count > 0 {
+        keys_checked.fetch_add(local_count, Ordering::Relaxed);
+        progress_bar.inc(local_count);
+    }
+    if let Some(positions) = &progress_positions {
+        positions.lock().unwrap().insert(batch_idx, current);
+    }
+
+    Ok(None)
+}
+
+/// Loads a `--targets-file` (one address or 40-char hash160 hex per line,
+/// blank lines and `#`-comments ignored) into a Bloom filter prefilter plus
+/// an exact `HashSet` for confirming filter hits.
+fn load_targets_file(path: &str, fp_rate: f64) -> Result<(Hash160BloomFilter, HashSet<[u8; 20]>)> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read targets file '{}': {}", path, e))?;
+
+    let mut hash160s = HashSet::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let is_raw_hash160 = line.len() == 40 && line.chars().all(|c| c.is_ascii_hexdigit());
+        let hash160 = if is_raw_hash160 {
+            parse_hash160_hex(line)?
+        } else {
+            address_to_hash160(line)
+                .map_err(|e| anyhow::anyhow!("Line {} in '{}': {}", line_no + 1, path, e))?
+        };
+        hash160s.insert(hash160);
+    }
+
+    if hash160s.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Targets file '{}' contained no valid addresses or hash160s",
+            path
+        ));
+    }
+
+    let mut bloom = Hash160BloomFilter::new(hash160s.len(), fp_rate);
+    for hash160 in &hash160s {
+        bloom.insert(hash160);
+    }
+
+    println!(
+        "Loaded {} target hash160s from '{}' into a Bloom filter (fp rate ~{:.1e})",
+        hash160s.len(),
+        path,
+        fp_rate
+    );
+
+    Ok((bloom, hash160s))
+}
+
+fn hash160_to_hex(hash160: &[u8; 20]) -> String {
+    hash160.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn search_range_bloom(
+    start: BigUint,
+    end: BigUint,
+    bloom: &Hash160BloomFilter,
+    exact_hash160s: &HashSet<[u8; 20]>,
+    batch_size: u64,
+    found: Arc<AtomicBool>,
+    keys_checked: Arc<AtomicU64>,
+    progress_bar: ProgressBar,
+) -> Result<Option<(String, String)>> {
+    let range_size = &end - &start + 1u32;
+    let batch_count = (range_size.clone() / batch_size + 1u32)
+        .try_into()
+        .unwrap_or(usize::MAX);
+
+    let result = (0..batch_count)
+        .into_par_iter()
+        .map(|batch_idx| {
+            if found.load(Ordering::Relaxed) {
+                return Ok(None);
+            }
+
+            let batch_start = &start + (batch_idx as u64) * batch_size;
+            let batch_end = std::cmp::min(batch_start.clone() + batch_size - 1u32, end.clone());
+
+            search_batch_bloom(
+                batch_start,
+                batch_end,
+                bloom,
+                exact_hash160s,
+                found.clone(),
+                keys_checked.clone(),
+                progress_bar.clone(),
+            )
+        })
+        .find_map_first(|result| match result {
+            Ok(Some(found_key)) => {
+                found.store(true, Ordering::Relaxed);
+                Some(Ok(Some(found_key)))
+            }
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        });
+
+    match result {
+        Some(r) => r,
+        None => Ok(None),
+    }
+}
+
+fn search_batch_bloom(
+    start: BigUint,
+    end: BigUint,
+    bloom: &Hash160BloomFilter,
+    exact_hash160s: &HashSet<[u8; 20]>,
+    found: Arc<AtomicBool>,
+    keys_checked: Arc<AtomicU64>,
+    progress_bar: ProgressBar,
+) -> Result<Option<(String, String)>> {
+    let mut current = start;
+    let mut local_count = 0u64;
+
+    while current <= end && !found.load(Ordering::Relaxed) {
+        if let Ok(hash160s) = private_key_to_hash160s(&current) {
+            for hash160 in &hash160s {
+                if bloom.might_contain(hash160) && exact_hash160s.contains(hash160) {
+                    let hex_key = format!("{:064x}", current);
+                    return Ok(Some((hex_key, hash160_to_hex(hash160))));
+                }
+            }
+        }
+
+        current += 1u32;
+        local_count += 1;
+
         if local_count % 10000 == 0 {
             keys_checked.fetch_add(local_count, Ordering::Relaxed);
             progress_bar.inc(local_count);
@@ -2,58 +2,341 @@ use crate::bitcoin_utils::private_key_to_addresses;
 use anyhow::Result;
 use indicatif::ProgressBar;
 use num_bigint::BigUint;
-use num_traits::{One, Zero};
-use rand::Rng;
+use num_traits::{Num, One, Zero};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use rand_distr::{Distribution, WeightedAliasIndex};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 
 
+/// Tuning parameters for the Lévy-flight step sampler used by the random walk.
+///
+/// `alpha` is the Pareto tail index (1 < alpha <= 3): smaller values give a
+/// heavier tail and more frequent large jumps, larger values stay closer to
+/// `x_min` most of the time. `x_min` is the minimum step magnitude (the local
+/// scale); `adapt_random_walk` periodically varies it to trade off local
+/// density against coverage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevyParams {
+    pub alpha: f64,
+    pub x_min: u64,
+}
+
+impl Default for LevyParams {
+    fn default() -> Self {
+        LevyParams {
+            alpha: 1.5,
+            x_min: 1,
+        }
+    }
+}
+
+/// Which non-crypto/crypto RNG backend a walk draws from. `Pcg64` favors raw
+/// throughput; `ChaCha20` favors stream quality for longer or more sensitive
+/// runs. Both implement `RngCore`, so `adaptive_random_walk_search` doesn't
+/// need to know which one it was handed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngBackend {
+    Pcg64,
+    ChaCha20,
+}
+
+/// A `RngCore` sum type over the supported backends, so the parallel driver
+/// can pick a backend per run while still handing a single concrete type to
+/// the generic search function.
+pub enum WalkRng {
+    Pcg64(Pcg64),
+    ChaCha20(ChaCha20Rng),
+}
+
+impl WalkRng {
+    pub fn seed_from_u64(backend: RngBackend, seed: u64) -> Self {
+        match backend {
+            RngBackend::Pcg64 => WalkRng::Pcg64(Pcg64::seed_from_u64(seed)),
+            RngBackend::ChaCha20 => WalkRng::ChaCha20(ChaCha20Rng::seed_from_u64(seed)),
+        }
+    }
+
+    pub fn from_entropy(backend: RngBackend) -> Self {
+        match backend {
+            RngBackend::Pcg64 => WalkRng::Pcg64(Pcg64::from_entropy()),
+            RngBackend::ChaCha20 => WalkRng::ChaCha20(ChaCha20Rng::from_entropy()),
+        }
+    }
+}
+
+impl RngCore for WalkRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            WalkRng::Pcg64(r) => r.next_u32(),
+            WalkRng::ChaCha20(r) => r.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            WalkRng::Pcg64(r) => r.next_u64(),
+            WalkRng::ChaCha20(r) => r.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            WalkRng::Pcg64(r) => r.fill_bytes(dest),
+            WalkRng::ChaCha20(r) => r.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            WalkRng::Pcg64(r) => r.try_fill_bytes(dest),
+            WalkRng::ChaCha20(r) => r.try_fill_bytes(dest),
+        }
+    }
+}
+
+/// Wraps an RNG and periodically reseeds it from OS entropy, so multi-hour
+/// walks don't run deep enough into a single stream to exhibit short-period
+/// artifacts. `reseed_fn` is called to produce a freshly-seeded replacement
+/// once `reseed_after_bytes` bytes have been drawn from the current one.
+pub struct ReseedingRng<R, F> {
+    inner: R,
+    bytes_since_reseed: u64,
+    reseed_after_bytes: u64,
+    reseed_fn: F,
+}
+
+impl<R: RngCore, F: FnMut() -> R> ReseedingRng<R, F> {
+    pub fn new(inner: R, reseed_after_bytes: u64, reseed_fn: F) -> Self {
+        ReseedingRng {
+            inner,
+            bytes_since_reseed: 0,
+            reseed_after_bytes,
+            reseed_fn,
+        }
+    }
+
+    fn maybe_reseed(&mut self) {
+        if self.bytes_since_reseed >= self.reseed_after_bytes {
+            self.inner = (self.reseed_fn)();
+            self.bytes_since_reseed = 0;
+        }
+    }
+}
+
+impl<R: RngCore, F: FnMut() -> R> RngCore for ReseedingRng<R, F> {
+    fn next_u32(&mut self) -> u32 {
+        self.maybe_reseed();
+        self.bytes_since_reseed += 4;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.maybe_reseed();
+        self.bytes_since_reseed += 8;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.maybe_reseed();
+        self.bytes_since_reseed += dest.len() as u64;
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.maybe_reseed();
+        self.bytes_since_reseed += dest.len() as u64;
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+/// Local-scale variants for the Lévy sampler's `x_min`, cycled through by
+/// `adapt_random_walk` as a walk adapts.
+const X_MIN_VARIANTS: [u64; 16] = [
+    1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597,
+];
+
+/// Online, self-reweighting sampler over `X_MIN_VARIANTS`.
+///
+/// Each variant starts with equal weight. A variant's weight is increased
+/// whenever it leads to a fresh (not-yet-visited) position and decayed
+/// whenever it leads into a detected cycle, so the alias table drifts toward
+/// whichever step scales are actually productive for this puzzle range
+/// without ever dropping a variant to zero (rare large jumps stay possible).
+struct StepWeightSampler {
+    weights: Vec<f64>,
+    alias: WeightedAliasIndex<f64>,
+}
+
+impl StepWeightSampler {
+    fn new() -> Self {
+        let weights = vec![1.0; X_MIN_VARIANTS.len()];
+        let alias = WeightedAliasIndex::new(weights.clone())
+            .expect("uniform weights are always a valid alias table");
+        StepWeightSampler { weights, alias }
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> usize {
+        self.alias.sample(rng)
+    }
+
+    fn reward(&mut self, variant_idx: usize) {
+        self.weights[variant_idx] += 1.0;
+    }
+
+    fn decay(&mut self, variant_idx: usize) {
+        self.weights[variant_idx] = (self.weights[variant_idx] * 0.5).max(0.01);
+    }
+
+    /// Rebuilds the alias table from the current weights; call after
+    /// `reward`/`decay` have updated them so sampling reflects the latest data.
+    fn refresh(&mut self) {
+        self.alias = WeightedAliasIndex::new(self.weights.clone())
+            .unwrap_or_else(|_| WeightedAliasIndex::new(vec![1.0; X_MIN_VARIANTS.len()]).unwrap());
+    }
+}
+
+/// Draws a heavy-tailed (Pareto) step magnitude via inverse transform
+/// sampling: `x = x_min / u^(1/alpha)` for `u ~ Uniform(0, 1)`. Most draws
+/// land near `x_min`, with rare much larger jumps from the heavy tail. Since
+/// `u` is at least `f64::EPSILON`, a draw is at most about
+/// `x_min * EPSILON^(-1/alpha)` (roughly `2.7e10 * x_min` at `alpha = 1.5`),
+/// and the magnitude is not scaled further, so on ranges much wider than that
+/// even the largest jumps cover only a small fraction of `range_size`. The
+/// result is reduced modulo `range_size` and clamped to at least 1 so the
+/// walk always moves.
+fn sample_levy_step(range_size: &BigUint, alpha: f64, x_min: u64, rng: &mut impl Rng) -> BigUint {
+    let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let magnitude = x_min as f64 / u.powf(1.0 / alpha);
+
+    let base = if magnitude.is_finite() {
+        magnitude.min(u128::MAX as f64) as u128
+    } else {
+        u128::MAX
+    };
+
+    let step = BigUint::from(base) % range_size;
+    if step.is_zero() {
+        BigUint::one()
+    } else {
+        step
+    }
+}
+
+/// A walk's position and adaptation state at a point in time, captured so a
+/// walk that was stopped early (`--max-time` or Ctrl-C) can be resumed from
+/// roughly where it left off instead of restarting from a fresh random
+/// position and losing everything the sampler had learned. Cycle-detection
+/// history (`seen`) is intentionally not captured: resuming with it cleared
+/// just costs a few wasted steps re-discovering nearby cycles, which is far
+/// cheaper than serializing a potentially large position set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalkState {
+    pub position_hex: String,
+    pub variant_idx: usize,
+    pub x_min: u64,
+    pub step_weights: Vec<f64>,
+    pub total_adaptations: u64,
+}
+
+/// Result of a single adaptive random walk: whatever key/address it found (if
+/// any), the weight the online reweighting sampler settled on for each of
+/// `X_MIN_VARIANTS`, and the state to resume from if it was stopped early.
+#[derive(Debug, Clone)]
+pub struct WalkOutcome {
+    pub found: Option<(String, String)>,
+    pub step_weights: Vec<f64>,
+    pub final_state: WalkState,
+}
+
 /// Advanced adaptive random walk search with configurable adaptation interval
-pub fn adaptive_random_walk_search(
+///
+/// Generic over the RNG so callers can choose a fast non-crypto generator
+/// (e.g. `Pcg64`) for raw throughput, a crypto generator (`ChaCha20`) when
+/// stream quality matters, or wrap either in [`ReseedingRng`] for long runs.
+/// A seeded `R` (via `SeedableRng::seed_from_u64`) makes the walk
+/// reproducible: the same seed and RNG type always produce the same sequence
+/// of positions and step sizes, which lets a run be replayed for debugging or
+/// split deterministically across machines.
+pub fn adaptive_random_walk_search<R: Rng>(
     start_range: &BigUint,
     end_range: &BigUint,
     targets: &HashSet<String>,
     max_iter: usize,
     adapt_interval: usize,
+    rng: &mut R,
+    levy: LevyParams,
     progress_bar: Option<ProgressBar>,
     keys_checked: Option<Arc<AtomicU64>>,
-) -> Result<Option<(String, String)>> {
-    let mut rng = rand::thread_rng();
+    stop: Arc<AtomicBool>,
+    initial_state: Option<WalkState>,
+) -> Result<WalkOutcome> {
     let mut seen = HashSet::new();
+    let mut sampler = StepWeightSampler::new();
+    if let Some(ref state) = initial_state {
+        sampler.weights = state.step_weights.clone();
+        sampler.refresh();
+    }
 
     // Use the range size as our modulus
     let range_size = end_range - start_range + 1u32;
     if range_size.is_zero() {
-        return Ok(None);
+        return Ok(WalkOutcome {
+            found: None,
+            step_weights: sampler.weights.clone(),
+            final_state: WalkState {
+                position_hex: "0".to_string(),
+                variant_idx: 0,
+                x_min: levy.x_min,
+                step_weights: sampler.weights,
+                total_adaptations: 0,
+            },
+        });
     }
 
-    // Initialize random walk parameters
-    let mut step_size = BigUint::from(rng.gen_range(1u32..100u32));
-    let mut position = BigUint::from(rng.r#gen::<u64>()) % &range_size;
+    // Initialize random walk parameters, either fresh or resumed from a
+    // checkpointed WalkState.
+    let (mut variant_idx, mut x_min, mut position, mut total_adaptations) = match &initial_state {
+        Some(state) => (
+            state.variant_idx,
+            state.x_min,
+            BigUint::from_str_radix(&state.position_hex, 16).unwrap_or_default() % &range_size,
+            state.total_adaptations,
+        ),
+        None => {
+            let variant_idx = sampler.sample(rng);
+            let x_min = X_MIN_VARIANTS[variant_idx].max(levy.x_min);
+            let position = BigUint::from(rng.r#gen::<u64>()) % &range_size;
+            (variant_idx, x_min, position, 0)
+        }
+    };
     let mut iterations_since_adapt = 0;
-    let mut total_adaptations = 0;
     let mut local_count = 0u64;
 
-    // Step size variants for adaptation
-    let step_variants = [
-        1u32, 2u32, 3u32, 5u32, 8u32, 13u32, 21u32, 34u32, 55u32, 89u32,
-        144u32, 233u32, 377u32, 610u32, 987u32, 1597u32
-    ];
-
     for i in 0..max_iter {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+
         // Random direction with slight forward bias
         let direction = if rng.r#gen::<f64>() < 0.6 { 1i32 } else { -1i32 };
-        
+
+        // Draw a fresh Lévy-flight step for this move
+        let step = sample_levy_step(&range_size, levy.alpha, x_min, rng);
+
         // Apply random step
         if direction > 0 {
-            position = (&position + &step_size) % &range_size;
+            position = (&position + &step) % &range_size;
         } else {
             // Handle negative direction properly
-            if step_size <= position {
-                position -= &step_size;
+            if step <= position {
+                position -= &step;
             } else {
-                let step_mod = &step_size % &range_size;
+                let step_mod = &step % &range_size;
                 if step_mod <= position {
                     position -= &step_mod;
                 } else {
@@ -67,21 +350,37 @@ pub fn adaptive_random_walk_search(
 
         // Check if we've seen this key before (cycle detection)
         if !seen.insert(position.clone()) {
-            // Cycle detected, jump to new random position and change step
+            // Cycle detected: this variant led us back into known territory,
+            // decay its weight and pick a new one to try.
+            sampler.decay(variant_idx);
             position = BigUint::from(rng.r#gen::<u64>()) % &range_size;
-            step_size = BigUint::from(step_variants[rng.gen_range(0..step_variants.len())]);
+            variant_idx = sampler.sample(rng);
+            x_min = X_MIN_VARIANTS[variant_idx];
             seen.clear();
             total_adaptations += 1;
             iterations_since_adapt = 0;
             continue;
         }
 
+        // Fresh position: reward the variant that produced it
+        sampler.reward(variant_idx);
+
         // Test the private key against target addresses
         if let Ok(addresses) = private_key_to_addresses(&private_key) {
             for address in addresses {
                 if targets.contains(&address) {
                     let hex_key = format!("{:064x}", private_key);
-                    return Ok(Some((hex_key, address)));
+                    return Ok(WalkOutcome {
+                        found: Some((hex_key, address)),
+                        step_weights: sampler.weights.clone(),
+                        final_state: WalkState {
+                            position_hex: format!("{:x}", position),
+                            variant_idx,
+                            x_min,
+                            step_weights: sampler.weights,
+                            total_adaptations,
+                        },
+                    });
                 }
             }
         }
@@ -100,20 +399,20 @@ pub fn adaptive_random_walk_search(
             local_count = 0;
         }
 
-        // Adaptive step changing: change step size periodically
+        // Adaptive scale changing: refresh the alias table and draw a new variant
         if iterations_since_adapt >= adapt_interval {
-            adapt_random_walk(&mut step_size, &step_variants, &mut rng);
-            
+            adapt_random_walk(&mut variant_idx, &mut x_min, &mut sampler, rng);
+
             // Occasionally clear seen positions to explore previously visited areas
             if total_adaptations % 4 == 0 {
                 seen.clear();
             }
-            
+
             // Slightly randomize position to avoid getting stuck
             if rng.r#gen::<f64>() < 0.3 {
                 position = (position + BigUint::from(rng.r#gen::<u32>())) % &range_size;
             }
-            
+
             iterations_since_adapt = 0;
             total_adaptations += 1;
         }
@@ -122,22 +421,11 @@ pub fn adaptive_random_walk_search(
         if i > 0 && i % (max_iter / 8) == 0 {
             if rng.r#gen::<f64>() < 0.25 {
                 position = BigUint::from(rng.r#gen::<u64>()) % &range_size;
-                step_size = BigUint::from(step_variants[rng.gen_range(0..step_variants.len())]);
+                variant_idx = sampler.sample(rng);
+                x_min = X_MIN_VARIANTS[variant_idx];
                 seen.clear();
             }
         }
-
-        // Dynamic step size adjustment based on progress
-        if i % 5000 == 0 && i > 0 {
-            // Increase step size if we haven't found anything in a while
-            if rng.r#gen::<f64>() < 0.4 {
-                let multiplier = rng.gen_range(2u32..10u32);
-                step_size = (&step_size * multiplier) % &range_size;
-                if step_size.is_zero() {
-                    step_size = BigUint::one();
-                }
-            }
-        }
     }
 
     // Final progress update for remaining local count
@@ -150,30 +438,57 @@ pub fn adaptive_random_walk_search(
         }
     }
 
-    Ok(None)
+    Ok(WalkOutcome {
+        found: None,
+        step_weights: sampler.weights.clone(),
+        final_state: WalkState {
+            position_hex: format!("{:x}", position),
+            variant_idx,
+            x_min,
+            step_weights: sampler.weights,
+            total_adaptations,
+        },
+    })
 }
 
-/// Adapts the random walk step size to a new variant
+/// Refreshes the alias table from the sampler's latest weights and draws the
+/// next `x_min` variant from it, with a small random multiplier layered on
+/// top so the walk doesn't collapse onto a single exact step size.
 fn adapt_random_walk(
-    step_size: &mut BigUint,
-    step_variants: &[u32],
+    variant_idx: &mut usize,
+    x_min: &mut u64,
+    sampler: &mut StepWeightSampler,
     rng: &mut impl Rng,
 ) {
-    // Choose new step size
-    let base_step = step_variants[rng.gen_range(0..step_variants.len())];
-    
-    // Add some randomness to the step
-    let random_factor = rng.gen_range(1u32..20u32);
-    *step_size = BigUint::from(base_step * random_factor);
-    
-    // Occasionally use very large steps for long jumps
-    if rng.r#gen::<f64>() < 0.1 {
-        *step_size *= BigUint::from(rng.gen_range(100u32..1000u32));
-    }
+    sampler.refresh();
+    *variant_idx = sampler.sample(rng);
+    let base = X_MIN_VARIANTS[*variant_idx];
+    let random_factor = rng.gen_range(1u64..20u64);
+    *x_min = base * random_factor;
 }
 
 
 /// Multi-threaded Adaptive Random Walk search with progress tracking
+///
+/// When `seed` is `Some(base_seed)`, each walk derives its own stream via
+/// `base_seed.wrapping_add(walk_id)`, so the full set of `num_walks` runs on
+/// distinct streams and explores the same territory on every run given the
+/// same seed and walk count (walks share one range, so their positions can
+/// still coincide). `None` seeds each walk from OS entropy instead. `backend`
+/// selects the underlying generator (see [`RngBackend`]); `reseed_after_bytes`
+/// wraps it in a [`ReseedingRng`] that refreshes from OS entropy periodically,
+/// for runs long enough that a single stream's period could matter. Once a
+/// walk has reseeded, the run no longer follows from `seed` alone.
+///
+/// `stop` is the same stop flag the caller uses elsewhere (e.g. a Ctrl-C
+/// handler or a `--max-time` watchdog): every walk checks it and breaks
+/// early, and this function also sets it itself once any walk finds a match
+/// so the others stop promptly instead of running to `max_iter_per_thread`.
+/// `initial_states` resumes walk `i` from `initial_states[i]` (from a
+/// previous run's checkpoint) when present; walks beyond the saved list
+/// start fresh. The second element of the returned tuple is every walk's
+/// final [`WalkState`], in walk-index order, for checkpointing the run if it
+/// stops without a match.
 pub fn parallel_adaptive_random_walk_search_with_progress(
     start_range: &BigUint,
     end_range: &BigUint,
@@ -181,38 +496,104 @@ pub fn parallel_adaptive_random_walk_search_with_progress(
     max_iter_per_thread: usize,
     num_walks: usize,
     adapt_interval: usize,
+    seed: Option<u64>,
+    backend: RngBackend,
+    reseed_after_bytes: Option<u64>,
+    levy: LevyParams,
     progress_bar: Option<ProgressBar>,
     keys_checked: Option<Arc<AtomicU64>>,
-) -> Result<Option<(String, String)>> {
+    stop: Arc<AtomicBool>,
+    initial_states: Option<Vec<WalkState>>,
+) -> Result<(Option<(String, String)>, Vec<WalkState>)> {
     use rayon::prelude::*;
 
     // Run multiple independent adaptive random walks in parallel
     // Each walk uses a different adaptation interval and starting position
-    let result = (0..num_walks)
+    let results: Vec<Result<WalkOutcome>> = (0..num_walks)
         .into_par_iter()
         .map(|walk_id| {
             // Vary adaptation interval per walk for better exploration
             let varied_adapt_interval = adapt_interval + (walk_id * 200);
-            adaptive_random_walk_search(
-                start_range, 
-                end_range, 
-                targets, 
-                max_iter_per_thread, 
-                varied_adapt_interval,
-                progress_bar.clone(),
-                keys_checked.clone()
-            )
+            let walk_seed = seed.map(|base_seed| base_seed.wrapping_add(walk_id as u64));
+            let base_rng = match walk_seed {
+                Some(s) => WalkRng::seed_from_u64(backend, s),
+                None => WalkRng::from_entropy(backend),
+            };
+            let initial_state = initial_states
+                .as_ref()
+                .and_then(|states| states.get(walk_id))
+                .cloned();
+
+            let outcome = if let Some(threshold) = reseed_after_bytes {
+                let mut rng = ReseedingRng::new(base_rng, threshold, move || {
+                    WalkRng::from_entropy(backend)
+                });
+                adaptive_random_walk_search(
+                    start_range,
+                    end_range,
+                    targets,
+                    max_iter_per_thread,
+                    varied_adapt_interval,
+                    &mut rng,
+                    levy,
+                    progress_bar.clone(),
+                    keys_checked.clone(),
+                    stop.clone(),
+                    initial_state,
+                )
+            } else {
+                let mut rng = base_rng;
+                adaptive_random_walk_search(
+                    start_range,
+                    end_range,
+                    targets,
+                    max_iter_per_thread,
+                    varied_adapt_interval,
+                    &mut rng,
+                    levy,
+                    progress_bar.clone(),
+                    keys_checked.clone(),
+                    stop.clone(),
+                    initial_state,
+                )
+            };
+
+            // Tell the other walks to stop as soon as this one finds a match.
+            if matches!(outcome, Ok(ref o) if o.found.is_some()) {
+                stop.store(true, Ordering::Relaxed);
+            }
+            outcome
         })
-        .find_map_first(|result| match result {
-            Ok(Some(found)) => Some(Ok(Some(found))),
-            Ok(None) => None,
-            Err(e) => Some(Err(e)),
-        });
+        .collect();
+
+    let mut found = None;
+    let mut walk_states = Vec::with_capacity(results.len());
+    let mut first_err = None;
+
+    for result in results {
+        match result {
+            Ok(outcome) => {
+                if found.is_none() && outcome.found.is_some() {
+                    found = outcome.found.clone();
+                    stop.store(true, Ordering::Relaxed);
+                }
+                walk_states.push(outcome.final_state);
+            }
+            Err(e) => {
+                if first_err.is_none() {
+                    first_err = Some(e);
+                }
+            }
+        }
+    }
 
-    match result {
-        Some(r) => r,
-        None => Ok(None),
+    if found.is_none() {
+        if let Some(e) = first_err {
+            return Err(e);
+        }
     }
+
+    Ok((found, walk_states))
 }
 
 #[cfg(test)]
@@ -225,20 +606,120 @@ mod tests {
         let end = BigUint::from(100u32);
         let targets = HashSet::new(); // Empty targets for test
         
-        let result = adaptive_random_walk_search(&start, &end, &targets, 1000, 100, None, None);
+        let mut rng = WalkRng::seed_from_u64(RngBackend::ChaCha20, 42);
+        let stop = Arc::new(AtomicBool::new(false));
+        let result = adaptive_random_walk_search(&start, &end, &targets, 1000, 100, &mut rng, LevyParams::default(), None, None, stop, None);
         assert!(result.is_ok());
-        assert!(result.unwrap().is_none()); // Should not find anything with empty targets
+        let outcome = result.unwrap();
+        assert!(outcome.found.is_none()); // Should not find anything with empty targets
+        assert_eq!(outcome.step_weights.len(), X_MIN_VARIANTS.len());
     }
-    
+
+    #[test]
+    fn test_adaptive_random_walk_search_is_deterministic_with_seed() {
+        let start = BigUint::from(1u32);
+        let end = BigUint::from(1_000_000u32);
+        let targets = HashSet::new();
+
+        let mut rng_a = WalkRng::seed_from_u64(RngBackend::ChaCha20, 7);
+        let mut rng_b = WalkRng::seed_from_u64(RngBackend::ChaCha20, 7);
+        let a = adaptive_random_walk_search(&start, &end, &targets, 500, 50, &mut rng_a, LevyParams::default(), None, None, Arc::new(AtomicBool::new(false)), None).unwrap();
+        let b = adaptive_random_walk_search(&start, &end, &targets, 500, 50, &mut rng_b, LevyParams::default(), None, None, Arc::new(AtomicBool::new(false)), None).unwrap();
+        assert_eq!(a.found, b.found);
+        assert_eq!(a.step_weights, b.step_weights);
+    }
+
+    #[test]
+    fn test_adaptive_random_walk_search_stops_when_flag_set() {
+        let start = BigUint::from(1u32);
+        let end = BigUint::from(1_000_000_000u64);
+        let targets = HashSet::new();
+
+        let mut rng = WalkRng::seed_from_u64(RngBackend::ChaCha20, 1);
+        let stop = Arc::new(AtomicBool::new(true)); // already stopped
+        let outcome = adaptive_random_walk_search(
+            &start, &end, &targets, 1_000_000, 100, &mut rng, LevyParams::default(), None, None,
+            stop, None,
+        )
+        .unwrap();
+
+        assert!(outcome.found.is_none());
+    }
+
+    #[test]
+    fn test_adaptive_random_walk_search_resumes_from_initial_state() {
+        let start = BigUint::from(1u32);
+        let end = BigUint::from(1_000_000u32);
+        let targets = HashSet::new();
+
+        let resumed_state = WalkState {
+            position_hex: "2a".to_string(),
+            variant_idx: 3,
+            x_min: 21,
+            step_weights: vec![2.0; X_MIN_VARIANTS.len()],
+            total_adaptations: 5,
+        };
+
+        let mut rng = WalkRng::seed_from_u64(RngBackend::ChaCha20, 7);
+        let outcome = adaptive_random_walk_search(
+            &start, &end, &targets, 10, 100, &mut rng, LevyParams::default(), None, None,
+            Arc::new(AtomicBool::new(false)), Some(resumed_state.clone()),
+        )
+        .unwrap();
+
+        // Resuming never loses ground: adaptation count only grows from here.
+        assert!(outcome.final_state.total_adaptations >= resumed_state.total_adaptations);
+        assert_eq!(outcome.step_weights.len(), resumed_state.step_weights.len());
+    }
+
+    #[test]
+    fn test_reseeding_rng_reseeds_after_threshold() {
+        let mut reseed_calls = 0u32;
+        let mut rng = ReseedingRng::new(Pcg64::seed_from_u64(1), 8, || {
+            reseed_calls += 1;
+            Pcg64::seed_from_u64(99)
+        });
+
+        // Two u64 draws (8 bytes each) should trigger at least one reseed.
+        let _ = rng.next_u64();
+        let _ = rng.next_u64();
+        assert!(reseed_calls >= 1);
+    }
+
+    #[test]
+    fn test_step_weight_sampler_reward_and_decay() {
+        let mut sampler = StepWeightSampler::new();
+        sampler.reward(0);
+        sampler.reward(0);
+        sampler.decay(1);
+        sampler.refresh();
+
+        assert!(sampler.weights[0] > sampler.weights[1]);
+    }
+
     #[test]
     fn test_adapt_random_walk() {
         let mut rng = rand::thread_rng();
-        let step_variants = [1u32, 5u32, 10u32];
-        let mut step_size = BigUint::from(1u32);
-        
-        adapt_random_walk(&mut step_size, &step_variants, &mut rng);
-        
-        // Step size should have changed
-        assert!(step_size > BigUint::zero());
+        let mut sampler = StepWeightSampler::new();
+        let mut variant_idx = 0usize;
+        let mut x_min = 1u64;
+
+        adapt_random_walk(&mut variant_idx, &mut x_min, &mut sampler, &mut rng);
+
+        // x_min should have been reassigned to a positive scale
+        assert!(x_min > 0);
+        assert!(variant_idx < X_MIN_VARIANTS.len());
+    }
+
+    #[test]
+    fn test_sample_levy_step_is_nonzero_and_in_range() {
+        let mut rng = rand::thread_rng();
+        let range_size = BigUint::from(1_000_000u32);
+
+        for _ in 0..100 {
+            let step = sample_levy_step(&range_size, 1.5, 1, &mut rng);
+            assert!(step >= BigUint::one());
+            assert!(step < range_size);
+        }
     }
 }
\ No newline at end of file